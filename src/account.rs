@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+
+use serde::{ Serialize, Deserialize };
+
+include!("split/structs/account/response.rs");
+
+include!("split/structs/account/response_deleted.rs");
+
+include!("split/structs/account/response_list.rs");
+
+#[derive(PartialEq)]
+pub(crate) enum Types {
+  CREATE(String),
+  RETRIEVE(String),
+  UPDATE(String, String),
+  DELETE(String),
+  REJECT(String, String),
+  LIST(String)
+}
+
+const ACCOUNT_URL: &str = "https://api.stripe.com/v1/accounts";
+
+impl Types {
+  pub(crate) fn create_send_request(&self, client: &reqwest::Client, secret: &str)-> reqwest::RequestBuilder {
+    let mut result = client
+      .post(self._get_url())
+      .basic_auth(secret, None::<&str>)
+      .header("Content-Type", "application/x-www-form-urlencoded");
+    
+    if let Some(r) = self._get_body() {
+      result = result.body(r);
+    }
+
+    result
+  }
+
+  pub(crate) fn create_delete_request(&self, client: &reqwest::Client, secret: &str)-> reqwest::RequestBuilder {
+    client
+      .delete(self._get_url())
+      .basic_auth(secret, None::<&str>)
+  }
+
+  pub(crate) fn create_get_request(&self, client: &reqwest::Client, secret: &str)-> reqwest::RequestBuilder {
+    let mut result = client
+      .get(self._get_url())
+      .basic_auth(secret, None::<&str>)
+      .header("Content-Type", "application/x-www-form-urlencoded");
+    
+    if let Some(r) = self._get_body() {
+      result = result.body(r);
+    }
+
+    result
+  }
+
+  fn _get_url(&self) -> String {
+    match self {
+      Self::CREATE(_) => ACCOUNT_URL.to_string(),
+      Self::RETRIEVE(id) => format!("{}/{}", ACCOUNT_URL, id),
+      Self::UPDATE(id, _) => format!("{}/{}", ACCOUNT_URL, id),
+      Self::DELETE(id) => format!("{}/{}", ACCOUNT_URL, id),
+      Self::REJECT(id, _) => format!("{}/{}/reject", ACCOUNT_URL, id),
+      Self::LIST(_) => ACCOUNT_URL.to_string()
+    }
+  }
+
+  fn _get_body(&self) -> Option<String> {
+    let body = match self {
+      Self::CREATE(body) => body,
+      Self::UPDATE(_, body) => body,
+      Self::REJECT(_, body) => body,
+      Self::LIST(body) => body,
+      _ => ""
+    };
+
+    if body.is_empty() {
+      None
+    } else {
+      Some(body.to_string())
+    }
+  }
+}
+
+pub struct Info<'a> {
+  pub(crate) r#type: Types,
+  pub(crate) secret_key: String,
+  pub(crate) reqwest_client: &'a reqwest::Client,
+  pub(crate) lenient: bool
+}
+
+impl Info<'_> {
+  /// Sends a "POST" request to Stripe's API.
+  pub async fn send(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.send_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "POST" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn send_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send()`. Please use the `get()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::DELETE(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send()`. Please use the `delete()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::LIST(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send()`. Please use the `get_list()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      _ => ()
+    };
+
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "GET" request to Stripe's API.
+  pub async fn get(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.get_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) => (),
+      Types::DELETE(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get()`. Please use the `delete()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::LIST(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get()`. Please use the `get_list()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      _ => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get()`. Please use the `send()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      }
+    };
+
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "GET" request to Stripe's API.
+  pub async fn get_list(&self) -> Result<ResponseList, (String, Option<crate::error::Info>)> {
+    self.get_list_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_list_with_meta(&self) -> Result<crate::meta::Response<ResponseList>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::LIST(_) => (),
+      Types::RETRIEVE(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_list()`. Please use the `get()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::DELETE(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_list()`. Please use the `delete()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      _ => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_list()`. Please use the `send()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      }
+    };
+
+    crate::helper::make_reqwest_with_meta::<ResponseList>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "DELETE" request to Stripe's API.
+  pub async fn delete(&self) -> Result<ResponseDeleted, (String, Option<crate::error::Info>)> {
+    self.delete_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "DELETE" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn delete_with_meta(&self) -> Result<crate::meta::Response<ResponseDeleted>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::DELETE(_) => (),
+      Types::RETRIEVE(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `delete()`. Please use the `get()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::LIST(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `delete()`. Please use the `get_list()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      _ => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `delete()`. Please use the `send()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      }
+    };
+
+    crate::helper::make_reqwest_with_meta::<ResponseDeleted>(self.r#type.create_delete_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "POST" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn send_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) | Types::LIST(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send_raw()`. Please use the `get_raw()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::DELETE(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send_raw()`. Please use the `delete()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      _ => ()
+    };
+
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), false).await
+  }
+
+  /// Sends a "GET" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn get_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) | Types::LIST(_) => (),
+      Types::DELETE(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_raw()`. Please use the `delete()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      _ => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_raw()`. Please use the `send_raw()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      }
+    };
+
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), false).await
+  }
+}
\ No newline at end of file
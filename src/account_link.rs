@@ -0,0 +1,67 @@
+use serde::{ Serialize, Deserialize };
+
+include!("split/structs/account_link/response.rs");
+
+#[derive(PartialEq)]
+pub(crate) enum Types {
+  CREATE(String)
+}
+
+const ACCOUNT_LINK_URL: &str = "https://api.stripe.com/v1/account_links";
+
+impl Types {
+  pub(crate) fn create_send_request(&self, client: &reqwest::Client, secret: &str)-> reqwest::RequestBuilder {
+    let mut result = client
+      .post(self._get_url())
+      .basic_auth(secret, None::<&str>)
+      .header("Content-Type", "application/x-www-form-urlencoded");
+    
+    if let Some(r) = self._get_body() {
+      result = result.body(r);
+    }
+
+    result
+  }
+
+  fn _get_url(&self) -> String {
+    match self {
+      Self::CREATE(_) => ACCOUNT_LINK_URL.to_string()
+    }
+  }
+
+  fn _get_body(&self) -> Option<String> {
+    let body = match self {
+      Self::CREATE(body) => body
+    };
+
+    if body.is_empty() {
+      None
+    } else {
+      Some(body.to_string())
+    }
+  }
+}
+
+pub struct Info<'a> {
+  pub(crate) r#type: Types,
+  pub(crate) secret_key: String,
+  pub(crate) reqwest_client: &'a reqwest::Client,
+  pub(crate) lenient: bool
+}
+
+impl Info<'_> {
+  /// Sends a "POST" request to Stripe's API.
+  pub async fn send(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.send_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "POST" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn send_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "POST" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn send_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), false).await
+  }
+}
\ No newline at end of file
@@ -4,6 +4,8 @@ include!("split/structs/balance_transaction/response.rs");
 
 include!("split/structs/balance_transaction/response_list.rs");
 
+include!("split/structs/balance_transaction/reconciliation.rs");
+
 #[derive(PartialEq)]
 pub(crate) enum Types {
   RETRIEVE(String),
@@ -29,7 +31,7 @@ impl Types {
   fn _get_url(&self) -> String {
     match self {
       Self::RETRIEVE(id) => format!("{}/{}", BALANCE_TRANSACTION_URL, id),
-      Self::LIST(_) => format!("{}", BALANCE_TRANSACTION_URL)
+      Self::LIST(_) => BALANCE_TRANSACTION_URL.to_string()
     }
   }
 
@@ -50,12 +52,18 @@ impl Types {
 pub struct Info<'a> {
   pub(crate) r#type: Types,
   pub(crate) secret_key: String,
-  pub(crate) reqwest_client: &'a reqwest::Client
+  pub(crate) reqwest_client: &'a reqwest::Client,
+  pub(crate) lenient: bool
 }
 
 impl Info<'_> {
   /// Sends a "GET" request to Stripe's API.
   pub async fn get(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.get_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::RETRIEVE(_) => (),
       Types::LIST(_) => {
@@ -66,11 +74,16 @@ impl Info<'_> {
       }
     };
     
-    crate::helper::make_reqwest::<Response>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key)).await
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
   }
 
   /// Sends a "GET" request to Stripe's API.
   pub async fn get_list(&self) -> Result<ResponseList, (String, Option<crate::error::Info>)> {
+    self.get_list_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_list_with_meta(&self) -> Result<crate::meta::Response<ResponseList>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::LIST(_) => (),
       Types::RETRIEVE(_) => {
@@ -81,6 +94,11 @@ impl Info<'_> {
       }
     };
     
-    crate::helper::make_reqwest::<ResponseList>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key)).await
+    crate::helper::make_reqwest_with_meta::<ResponseList>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "GET" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn get_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), false).await
   }
 }
\ No newline at end of file
@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+use serde::{ Serialize, Deserialize };
+
+include!("split/structs/charge/response.rs");
+
+include!("split/structs/charge/response_list.rs");
+
+include!("split/structs/charge/response_search.rs");
+
+#[derive(PartialEq)]
+pub(crate) enum Types {
+  CREATE(String),
+  RETRIEVE(String),
+  UPDATE(String, String),
+  CAPTURE(String, String),
+  LIST(String),
+  SEARCH(String)
+}
+
+const CHARGE_URL: &str = "https://api.stripe.com/v1/charges";
+
+impl Types {
+  pub(crate) fn create_send_request(&self, client: &reqwest::Client, secret: &str)-> reqwest::RequestBuilder {
+    let mut result = client
+      .post(self._get_url())
+      .basic_auth(secret, None::<&str>)
+      .header("Content-Type", "application/x-www-form-urlencoded");
+    
+    if let Some(r) = self._get_body() {
+      result = result.body(r);
+    }
+
+    result
+  }
+
+  pub(crate) fn create_get_request(&self, client: &reqwest::Client, secret: &str)-> reqwest::RequestBuilder {
+    let mut result = client
+      .get(self._get_url())
+      .basic_auth(secret, None::<&str>)
+      .header("Content-Type", "application/x-www-form-urlencoded");
+    
+    if let Some(r) = self._get_body() {
+      result = result.body(r);
+    }
+
+    result
+  }
+
+  fn _get_url(&self) -> String {
+    match self {
+      Self::CREATE(_) => CHARGE_URL.to_string(),
+      Self::RETRIEVE(id) => format!("{}/{}", CHARGE_URL, id),
+      Self::UPDATE(id, _) => format!("{}/{}", CHARGE_URL, id),
+      Self::CAPTURE(id, _) => format!("{}/{}/capture", CHARGE_URL, id),
+      Self::LIST(_) => CHARGE_URL.to_string(),
+      Self::SEARCH(_) => format!("{}/search", CHARGE_URL)
+    }
+  }
+
+  fn _get_body(&self) -> Option<String> {
+    let body = match self {
+      Self::CREATE(body) => body,
+      Self::UPDATE(_, body) => body,
+      Self::CAPTURE(_, body) => body,
+      Self::LIST(body) => body,
+      Self::SEARCH(body) => body,
+      _ => ""
+    };
+
+    if body.is_empty() {
+      None
+    } else {
+      Some(body.to_string())
+    }
+  }
+}
+
+pub struct Info<'a> {
+  pub(crate) r#type: Types,
+  pub(crate) secret_key: String,
+  pub(crate) reqwest_client: &'a reqwest::Client,
+  pub(crate) lenient: bool
+}
+
+impl Info<'_> {
+  /// Sends a "POST" request to Stripe's API.
+  pub async fn send(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.send_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "POST" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn send_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send()`. Please use the `get()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::LIST(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send()`. Please use the `get_list()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::SEARCH(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send()`. Please use the `get_search()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      _ => ()
+    };
+
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "GET" request to Stripe's API.
+  pub async fn get(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.get_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) => (),
+      Types::LIST(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get()`. Please use the `get_list()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::SEARCH(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get()`. Please use the `get_search()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      _ => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get()`. Please use the `send()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      }
+    };
+
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "GET" request to Stripe's API.
+  pub async fn get_list(&self) -> Result<ResponseList, (String, Option<crate::error::Info>)> {
+    self.get_list_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_list_with_meta(&self) -> Result<crate::meta::Response<ResponseList>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::LIST(_) => (),
+      Types::RETRIEVE(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_list()`. Please use the `get()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::SEARCH(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_list()`. Please use the `get_search()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      _ => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_list()`. Please use the `send()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      }
+    };
+
+    crate::helper::make_reqwest_with_meta::<ResponseList>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "GET" request to Stripe's API.
+  pub async fn get_search(&self) -> Result<ResponseSearch, (String, Option<crate::error::Info>)> {
+    self.get_search_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_search_with_meta(&self) -> Result<crate::meta::Response<ResponseSearch>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::SEARCH(_) => (),
+      Types::RETRIEVE(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_search()`. Please use the `get()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::LIST(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_search()`. Please use the `get_list()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      _ => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_search()`. Please use the `send()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      }
+    };
+
+    crate::helper::make_reqwest_with_meta::<ResponseSearch>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "POST" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn send_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) | Types::LIST(_) | Types::SEARCH(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send_raw()`. Please use the `get_raw()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      _ => ()
+    };
+
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), false).await
+  }
+
+  /// Sends a "GET" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn get_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) | Types::LIST(_) | Types::SEARCH(_) => (),
+      _ => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_raw()`. Please use the `send_raw()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      }
+    };
+
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), false).await
+  }
+}
\ No newline at end of file
@@ -7,36 +7,127 @@ pub struct Client {
   pub secret_key: String,
   // The `reqwest::Client` used to make requests to Stripe's API.
   #[doc(hidden)]
-  pub reqwest_client: reqwest::Client
+  pub reqwest_client: reqwest::Client,
+  /// If `true`, values that cannot be decoded (e.g. an unexpected `null` or a wrong type) are replaced with `None` or their default value instead of failing the whole request.
+  /// The paths of all replaced values are logged and available in `meta::Meta::defaulted`.
+  pub lenient: bool
 }
 
+#[cfg(feature = "account")]
+include!("split/implementations/client/account.rs");
+
+#[cfg(feature = "account")]
+include!("split/implementations/client/account_link.rs");
+
+#[cfg(feature = "application_fee")]
+include!("split/implementations/client/application_fee.rs");
+
+#[cfg(feature = "application_fee")]
+include!("split/implementations/client/application_fee_refund.rs");
+
 #[cfg(feature = "balance")]
 include!("split/implementations/client/balance.rs");
 
 #[cfg(feature = "balance_transaction")]
 include!("split/implementations/client/balance_transaction.rs");
 
+#[cfg(feature = "account")]
+include!("split/implementations/client/capability.rs");
+
+#[cfg(feature = "customer_balance")]
+include!("split/implementations/client/cash_balance.rs");
+
+#[cfg(feature = "customer_balance")]
+include!("split/implementations/client/cash_balance_transaction.rs");
+
+#[cfg(feature = "charge")]
+include!("split/implementations/client/charge.rs");
+
+#[cfg(feature = "checkout")]
+include!("split/implementations/client/checkout.rs");
+
+#[cfg(feature = "customer_balance")]
+include!("split/implementations/client/customer_balance_transaction.rs");
+
 #[cfg(feature = "dispute")]
 include!("split/implementations/client/dispute.rs");
 
+#[cfg(feature = "export")]
+include!("split/implementations/client/export.rs");
+
+#[cfg(feature = "account")]
+include!("split/implementations/client/external_account.rs");
+
+#[cfg(feature = "file")]
+include!("split/implementations/client/file.rs");
+
+#[cfg(feature = "file")]
+include!("split/implementations/client/file_link.rs");
+
+#[cfg(feature = "invoice")]
+include!("split/implementations/client/invoice.rs");
+
+#[cfg(feature = "invoice_item")]
+include!("split/implementations/client/invoice_item.rs");
+
+#[cfg(feature = "account")]
+include!("split/implementations/client/login_link.rs");
+
 #[cfg(feature = "mandate")]
 include!("split/implementations/client/mandate.rs");
 
 #[cfg(feature = "payment_intent")]
 include!("split/implementations/client/payment_intent.rs");
 
+#[cfg(feature = "payment_link")]
+include!("split/implementations/client/payment_link.rs");
+
 #[cfg(feature = "payout")]
 include!("split/implementations/client/payout.rs");
 
+#[cfg(feature = "account")]
+include!("split/implementations/client/person.rs");
+
+#[cfg(feature = "price")]
+include!("split/implementations/client/price.rs");
+
+#[cfg(feature = "product")]
+include!("split/implementations/client/product.rs");
+
 #[cfg(feature = "refund")]
 include!("split/implementations/client/refund.rs");
 
+#[cfg(feature = "reporting")]
+include!("split/implementations/client/report_run.rs");
+
+#[cfg(feature = "reporting")]
+include!("split/implementations/client/report_type.rs");
+
+#[cfg(feature = "setup_intent")]
+include!("split/implementations/client/setup_intent.rs");
+
+#[cfg(feature = "subscription")]
+include!("split/implementations/client/subscription.rs");
+
+#[cfg(feature = "subscription_item")]
+include!("split/implementations/client/subscription_item.rs");
+
+#[cfg(feature = "transfer")]
+include!("split/implementations/client/transfer.rs");
+
+#[cfg(feature = "transfer")]
+include!("split/implementations/client/transfer_reversal.rs");
+
+#[cfg(feature = "usage_record")]
+include!("split/implementations/client/usage_record.rs");
+
 impl Client {
   /// Create a new `Client`.
   pub fn new(secret_key: &str) -> Self {
     Self {
       secret_key: secret_key.to_string(),
-      reqwest_client: reqwest::Client::new()
+      reqwest_client: reqwest::Client::new(),
+      lenient: false
     }
   }
 }
\ No newline at end of file
@@ -6,10 +6,14 @@ include!("split/structs/dispute/response.rs");
 
 include!("split/structs/dispute/response_list.rs");
 
+include!("split/structs/dispute/parameters.rs");
+
+#[allow(non_camel_case_types)]
 #[derive(PartialEq)]
 pub(crate) enum Types {
   RETRIEVE(String),
   UPDATE(String, String),
+  UPDATE_EVIDENCE(String, Box<EvidenceParams>),
   CLOSE(String),
   LIST(String)
 }
@@ -47,22 +51,24 @@ impl Types {
     match self {
       Self::RETRIEVE(id) => format!("{}/{}", DISPUTE_URL, id),
       Self::UPDATE(id, _) => format!("{}/{}", DISPUTE_URL, id),
+      Self::UPDATE_EVIDENCE(id, _) => format!("{}/{}", DISPUTE_URL, id),
       Self::CLOSE(id) => format!("{}/{}/close", DISPUTE_URL, id),
-      Self::LIST(_) => format!("{}", DISPUTE_URL)
+      Self::LIST(_) => DISPUTE_URL.to_string()
     }
   }
 
   fn _get_body(&self) -> Option<String> {
     let body = match self {
-      Self::UPDATE(_, body) => body,
-      Self::LIST(body) => body,
-      _ => ""
+      Self::UPDATE(_, body) => body.to_string(),
+      Self::UPDATE_EVIDENCE(_, params) => params.to_string(),
+      Self::LIST(body) => body.to_string(),
+      _ => String::new()
     };
 
     if body.is_empty() {
       None
     } else {
-      Some(body.to_string())
+      Some(body)
     }
   }
 }
@@ -70,12 +76,18 @@ impl Types {
 pub struct Info<'a> {
   pub(crate) r#type: Types,
   pub(crate) secret_key: String,
-  pub(crate) reqwest_client: &'a reqwest::Client
+  pub(crate) reqwest_client: &'a reqwest::Client,
+  pub(crate) lenient: bool
 }
 
 impl Info<'_> {
   /// Sends a "POST" request to Stripe's API.
   pub async fn send(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.send_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "POST" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn send_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::RETRIEVE(_) => {
         if log::log_enabled!(log::Level::Error) {
@@ -89,14 +101,20 @@ impl Info<'_> {
         }
         return Err(("This function is not compatible with the selected type".to_string(), None));
       },
+      Types::UPDATE_EVIDENCE(_, ref params) => params.validate().map_err(|r| (r, None))?,
       _ => ()
     };
 
-    crate::helper::make_reqwest::<Response>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key)).await
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), self.lenient).await
   }
   
   /// Sends a "GET" request to Stripe's API.
   pub async fn get(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.get_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::RETRIEVE(_) => (),
       Types::LIST(_) => {
@@ -113,11 +131,16 @@ impl Info<'_> {
       }
     };
     
-    crate::helper::make_reqwest::<Response>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key)).await
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
   }
 
   /// Sends a "GET" request to Stripe's API.
   pub async fn get_list(&self) -> Result<ResponseList, (String, Option<crate::error::Info>)> {
+    self.get_list_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_list_with_meta(&self) -> Result<crate::meta::Response<ResponseList>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::LIST(_) => (),
       Types::RETRIEVE(_) => {
@@ -134,6 +157,37 @@ impl Info<'_> {
       }
     };
     
-    crate::helper::make_reqwest::<ResponseList>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key)).await
+    crate::helper::make_reqwest_with_meta::<ResponseList>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "POST" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn send_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) | Types::LIST(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send_raw()`. Please use the `get_raw()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::UPDATE_EVIDENCE(_, ref params) => params.validate().map_err(|r| (r, None))?,
+      _ => ()
+    };
+
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), false).await
+  }
+
+  /// Sends a "GET" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn get_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) | Types::LIST(_) => (),
+      _ => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_raw()`. Please use the `send_raw()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      }
+    };
+
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), false).await
   }
 }
\ No newline at end of file
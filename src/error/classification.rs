@@ -0,0 +1,156 @@
+/// A rough classification of an error to decide how to handle it.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+  /// The payment was declined because it is (suspected to be) fraudulent, e.g. a lost or stolen card.
+  Fraud,
+  /// The card or account has insufficient funds.
+  InsufficientFunds,
+  /// The customer has to authenticate the payment, e.g. with 3D Secure.
+  AuthenticationRequired,
+  /// The card has expired.
+  ExpiredCard,
+  /// The customer entered incorrect card details, e.g. a wrong number, CVC or ZIP code.
+  IncorrectDetails,
+  /// The card was declined for any other reason.
+  Declined,
+  /// The request failed because of the configuration of your integration or account, e.g. an invalid API key.
+  /// Someone has to fix this, retrying will not help.
+  ConfigError,
+  /// A temporary problem, e.g. too many requests or an error on Stripe's end.
+  Temporary,
+  /// The request has invalid parameters.
+  InvalidRequest,
+  /// Everything else.
+  Other
+}
+
+impl Info {
+  /// Returns `true` if the error is a card error.
+  pub fn is_card_error(&self) -> bool {
+    self.r#type == Types::CardError
+  }
+
+  /// Returns `true` if the same request can safely be sent again (preferably with an exponential backoff).
+  pub fn is_retryable(&self) -> bool {
+    match self.http_code {
+      HTTPCodes::TooManyRequests | HTTPCodes::ServerError(_) => return true,
+      _ => ()
+    };
+
+    if matches!(self.r#type, Types::ApiConnectionError | Types::ApiError | Types::RateLimitError) {
+      return true;
+    }
+
+    if matches!(self.code, Codes::LockTimeout | Codes::RateLimit | Codes::IdempotencyKeyInUse) {
+      return true;
+    }
+
+    matches!(
+      self.decline_code,
+      Some(DeclineCode::IssuerNotAvailable | DeclineCode::ProcessingError | DeclineCode::ReenterTransaction | DeclineCode::TryAgainLater)
+    )
+  }
+
+  /// Returns the category of the error.
+  pub fn category(&self) -> Category {
+    if let Some(r) = &self.decline_code {
+      return match r {
+        DeclineCode::Fraudulent
+          | DeclineCode::LostCard
+          | DeclineCode::StolenCard
+          | DeclineCode::PickupCard
+          | DeclineCode::MerchantBlacklist
+          | DeclineCode::RestrictedCard
+          | DeclineCode::SecurityViolation => Category::Fraud,
+        DeclineCode::InsufficientFunds
+          | DeclineCode::CardVelocityExceeded
+          | DeclineCode::WithdrawalCountLimitExceeded => Category::InsufficientFunds,
+        DeclineCode::AuthenticationRequired
+          | DeclineCode::OfflinePinRequired
+          | DeclineCode::OnlineOrOfflinePinRequired => Category::AuthenticationRequired,
+        DeclineCode::ExpiredCard => Category::ExpiredCard,
+        DeclineCode::IncorrectNumber
+          | DeclineCode::IncorrectCvc
+          | DeclineCode::IncorrectPin
+          | DeclineCode::IncorrectZip
+          | DeclineCode::InvalidCvc
+          | DeclineCode::InvalidExpiryMonth
+          | DeclineCode::InvalidExpiryYear
+          | DeclineCode::InvalidNumber
+          | DeclineCode::InvalidPin
+          | DeclineCode::PinTryExceeded => Category::IncorrectDetails,
+        DeclineCode::IssuerNotAvailable
+          | DeclineCode::ProcessingError
+          | DeclineCode::ReenterTransaction
+          | DeclineCode::TryAgainLater => Category::Temporary,
+        _ => Category::Declined
+      };
+    }
+
+    match self.code {
+      Codes::ExpiredCard => return Category::ExpiredCard,
+      Codes::InsufficientFunds | Codes::BalanceInsufficient => return Category::InsufficientFunds,
+      Codes::AuthenticationRequired
+        | Codes::PaymentIntentAuthenticationFailure
+        | Codes::SetupIntentAuthenticationFailure => return Category::AuthenticationRequired,
+      Codes::IncorrectCVC
+        | Codes::IncorrectNumber
+        | Codes::IncorrectZip
+        | Codes::IncorrectAddress
+        | Codes::InvalidCVC
+        | Codes::InvalidExpiryMonth
+        | Codes::InvalidExpiryYear
+        | Codes::InvalidNumber
+        | Codes::PostalCodeInvalid => return Category::IncorrectDetails,
+      Codes::CardDeclined | Codes::CardDeclineRateLimitExceeded => return Category::Declined,
+      Codes::ApiKeyExpired
+        | Codes::PlatformApiKeyExpired
+        | Codes::SecretKeyRequired
+        | Codes::LivemodeMismatch
+        | Codes::TestmodeChargesOnly
+        | Codes::PlatformAccountRequired
+        | Codes::NotAllowedOnStandardAccount
+        | Codes::TlsVersionUnsupported
+        | Codes::AccountInvalid
+        | Codes::InstantPayoutsConfigDisabled
+        | Codes::TransfersNotAllowed
+        | Codes::PayoutsNotAllowed => return Category::ConfigError,
+      Codes::LockTimeout | Codes::RateLimit => return Category::Temporary,
+      _ => ()
+    };
+
+    match self.r#type {
+      Types::AuthenticationError => return Category::ConfigError,
+      Types::ApiConnectionError | Types::ApiError | Types::RateLimitError => return Category::Temporary,
+      Types::CardError => return Category::Declined,
+      Types::InvalidRequestError | Types::IdempotencyError => return Category::InvalidRequest,
+      Types::None => ()
+    };
+
+    match self.http_code {
+      HTTPCodes::Unauthorized | HTTPCodes::Forbidden => Category::ConfigError,
+      HTTPCodes::TooManyRequests | HTTPCodes::ServerError(_) => Category::Temporary,
+      HTTPCodes::BadRequest | HTTPCodes::NotFound | HTTPCodes::Conflict => Category::InvalidRequest,
+      _ => Category::Other
+    }
+  }
+
+  /// Returns a message that can be shown to your customers.
+  /// 
+  /// Stripe's `message` is used for card errors, except when the card is (suspected to be) fraudulent.
+  /// In all other cases a generic message is returned, as these errors are not the customer's fault.
+  pub fn user_safe_message(&self) -> String {
+    match self.category() {
+      Category::Fraud => "Your card was declined.".to_string(),
+      _ if self.is_card_error() && !self.message.is_empty() => self.message.clone(),
+      Category::InsufficientFunds => "Your card has insufficient funds.".to_string(),
+      Category::AuthenticationRequired => "Your payment requires authentication.".to_string(),
+      Category::ExpiredCard => "Your card has expired.".to_string(),
+      Category::IncorrectDetails => "Your card details are incorrect.".to_string(),
+      Category::Declined => "Your card was declined.".to_string(),
+      Category::Temporary => "We could not process your payment. Please try again later.".to_string(),
+      Category::ConfigError | Category::InvalidRequest | Category::Other => "We could not process your payment.".to_string()
+    }
+  }
+}
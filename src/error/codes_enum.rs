@@ -1,7 +1,7 @@
 /// All available error codes from 01/08/2023
 /// 
 /// [Official Stripe error code list](https://stripe.com/docs/error-codes)
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum Codes {
   None,
@@ -46,6 +46,7 @@ pub enum Codes {
   ExpiredCard,
   IdempotencyKeyInUse,
   IncorrectAddress,
+  #[serde(rename = "incorrect_cvc")]
   IncorrectCVC,
   IncorrectNumber,
   IncorrectZip,
@@ -59,6 +60,7 @@ pub enum Codes {
   InvalidCardType,
   InvalidCharacters,
   InvalidChargeAmount,
+  #[serde(rename = "invalid_cvc")]
   InvalidCVC,
   InvalidExpiryMonth,
   InvalidExpiryYear,
@@ -145,21 +147,16 @@ pub enum Codes {
   TokenInUse,
   TransferSourceBalanceParametersMismatch,
   TransfersNotAllowed,
-  UrlInvalid
+  UrlInvalid,
+  /// An error code that is not (yet) part of this enumeration.
+  Unknown(String)
 }
 
 impl std::fmt::Display for Codes {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let mut result = String::new();
-
-    let self_to_string = format!("{:?}", self);
-    for (i, c) in self_to_string.chars().enumerate() {
-      if i > 0 && c.is_uppercase() {
-        result.push('_');
-      }
-      result.push(c);
+    match self {
+      Self::Unknown(r) => write!(f, "{}", r),
+      _ => write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
     }
-
-    write!(f, "{}", result.to_lowercase())
   }
 }
\ No newline at end of file
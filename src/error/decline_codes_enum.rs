@@ -0,0 +1,112 @@
+/// All available decline codes from 10/19/2026
+/// 
+/// [Official Stripe decline code list](https://stripe.com/docs/declines/codes)
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum DeclineCode {
+  /// The card was declined as the transaction requires authentication.
+  AuthenticationRequired,
+  /// The payment can't be authorized.
+  ApproveWithId,
+  /// The card was declined for an unknown reason.
+  CallIssuer,
+  /// The card doesn't support this type of purchase.
+  CardNotSupported,
+  /// The customer has exceeded the balance or credit limit available on their card.
+  CardVelocityExceeded,
+  /// The card doesn't support the specified currency.
+  CurrencyNotSupported,
+  /// The card was declined for an unknown reason.
+  DoNotHonor,
+  /// The card was declined for an unknown reason.
+  DoNotTryAgain,
+  /// A transaction with identical amount and credit card information was submitted very recently.
+  DuplicateTransaction,
+  /// The card has expired.
+  ExpiredCard,
+  /// The payment was declined because Stripe suspects that it's fraudulent.
+  Fraudulent,
+  /// The card was declined for an unknown reason or Stripe Radar blocked the payment.
+  GenericDecline,
+  /// The card number is incorrect.
+  IncorrectNumber,
+  /// The CVC number is incorrect.
+  IncorrectCvc,
+  /// The PIN entered is incorrect.
+  IncorrectPin,
+  /// The postal code is incorrect.
+  IncorrectZip,
+  /// The card has insufficient funds to complete the purchase.
+  InsufficientFunds,
+  /// The card, or account the card is connected to, is invalid.
+  InvalidAccount,
+  /// The payment amount is invalid, or exceeds the amount that's allowed.
+  InvalidAmount,
+  /// The CVC number is incorrect.
+  InvalidCvc,
+  /// The expiration month is invalid.
+  InvalidExpiryMonth,
+  /// The expiration year is invalid.
+  InvalidExpiryYear,
+  /// The card number is incorrect.
+  InvalidNumber,
+  /// The PIN entered is incorrect.
+  InvalidPin,
+  /// The card issuer couldn't be reached, so the payment couldn't be authorized.
+  IssuerNotAvailable,
+  /// The payment was declined because the card is reported lost.
+  LostCard,
+  /// The payment was declined because it matches a value on the Stripe user's block list.
+  MerchantBlacklist,
+  /// The card, or account the card is connected to, is invalid.
+  NewAccountInformationAvailable,
+  /// The card was declined for an unknown reason.
+  NoActionTaken,
+  /// The payment isn't permitted.
+  NotPermitted,
+  /// The card was declined because it requires a PIN.
+  OfflinePinRequired,
+  /// The card was declined because it requires a PIN.
+  OnlineOrOfflinePinRequired,
+  /// The card can't be used to make this payment (it's possible it was reported lost or stolen).
+  PickupCard,
+  /// The allowable number of PIN tries was exceeded.
+  PinTryExceeded,
+  /// An error occurred while processing the card.
+  ProcessingError,
+  /// The payment couldn't be processed by the issuer for an unknown reason.
+  ReenterTransaction,
+  /// The card can't be used to make this payment (it's possible it was reported lost or stolen).
+  RestrictedCard,
+  /// The card was declined for an unknown reason.
+  RevocationOfAllAuthorizations,
+  /// The card was declined for an unknown reason.
+  RevocationOfAuthorization,
+  /// The card was declined for an unknown reason.
+  SecurityViolation,
+  /// The card was declined for an unknown reason.
+  ServiceNotAllowed,
+  /// The payment was declined because the card is reported stolen.
+  StolenCard,
+  /// The card was declined for an unknown reason.
+  StopPaymentOrder,
+  /// A Stripe test card number was used.
+  TestmodeDecline,
+  /// The card was declined for an unknown reason.
+  TransactionNotAllowed,
+  /// The card was declined for an unknown reason.
+  TryAgainLater,
+  /// The customer has exceeded the balance, credit limit, or transaction amount limit available on their card.
+  WithdrawalCountLimitExceeded,
+  /// A decline code that is not (yet) part of this enumeration.
+  Unknown(String)
+}
+
+impl std::fmt::Display for DeclineCode {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Unknown(r) => write!(f, "{}", r),
+      _ => write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+    }
+  }
+}
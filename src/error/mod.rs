@@ -1,7 +1,7 @@
 use serde::{ Serialize, Deserialize };
 
 /// A list of possible HTTP errors.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum HTTPCodes {
   /// Something went wrong.
   None,
@@ -66,10 +66,12 @@ impl HTTPCodes {
 
 include!("codes_enum.rs");
 
+include!("decline_codes_enum.rs");
+
 /// All available error types from 01/08/2023
 /// 
 /// [Official Stripe error types list](https://stripe.com/docs/api/errors)
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum Types {
   /// No error type.
@@ -117,19 +119,38 @@ pub struct Info {
   pub r#type: Types,
   /// For some errors that could be handled programmatically, a short string indicating the [error code](https://stripe.com/docs/error-codes) reported.
   pub code: Codes,
+  /// For card errors resulting from a card issuer decline, a short string indicating the [card issuer’s reason for the decline](https://stripe.com/docs/declines#issuer-declines) if they provide one.
+  pub decline_code: Option<DeclineCode>,
+  /// A URL to more information about the [error code](https://stripe.com/docs/error-codes) reported.
+  pub doc_url: Option<String>,
   /// A human-readable message providing more details about the error.
   /// For card errors, these messages can be shown to your users.
   pub message: String,
   /// If the error is parameter-specific, the parameter related to the error.
   /// For example, you can use this to display a message near the correct form field.
   pub param: String,
+  /// The unique identifier of the request (`Request-Id` header).
+  /// Stripe support asks for this value when you report a problem with a request.
+  pub request_id: Option<String>,
+  /// A URL to the request log entry in your dashboard.
+  pub request_log_url: Option<String>,
+  /// For card errors, the ID of the failed charge.
+  pub charge: Option<String>,
+  /// The PaymentMethod object for errors returned on a request involving a PaymentMethod.
+  pub payment_method: Option<serde_json::Value>,
+  /// If the error is specific to the type of payment method, the payment method type that had a problem.
+  /// This field is only populated for invoice-related errors.
+  pub payment_method_type: Option<String>,
+  /// The SetupIntent object for errors returned on a request involving a SetupIntent.
+  #[cfg(feature = "setup_intent")]
+  pub setup_intent: Option<crate::setup_intent::Response>,
   /// The PaymentIntent object for errors returned on a request involving a PaymentIntent.
   #[cfg(feature = "payment_intent")]
   pub payment_intent: Option<crate::payment_intent::Response>
 }
 
 impl Info {
-  pub(crate) fn create(status: u16, json_text: &str) -> Option<Self> {
+  pub(crate) fn create(status: u16, request_id: Option<String>, json_text: &str) -> Option<Self> {
     let json = match serde_json::from_str::<serde_json::Value>(json_text) {
       Ok(r) => {
         if r["error"].is_null() {
@@ -151,18 +172,63 @@ impl Info {
     let r#type = serde_json::from_value::<Types>(type_json)
       .unwrap_or(Types::None);
 
-    let code_json = json["code"].clone();
-    let code = serde_json::from_value::<Codes>(code_json)
-      .unwrap_or(Codes::None);
+    let code = match json["code"].as_str() {
+      Some(r) => serde_json::from_value::<Codes>(json["code"].clone())
+        .unwrap_or_else(|_| Codes::Unknown(r.to_string())),
+      None => Codes::None
+    };
+
+    let decline_code = json["decline_code"].as_str().map(|r| {
+      serde_json::from_value::<DeclineCode>(json["decline_code"].clone())
+        .unwrap_or_else(|_| DeclineCode::Unknown(r.to_string()))
+    });
+
+    let payment_method = match &json["payment_method"] {
+      serde_json::Value::Null => None,
+      r => Some(r.clone())
+    };
+
+    #[cfg(feature = "setup_intent")]
+    let setup_intent = serde_json::from_value::<Option<crate::setup_intent::Response>>(json["setup_intent"].clone())
+      .unwrap_or(None);
 
     Some(Self {
       http_code: HTTPCodes::from_status(status),
       r#type,
       code,
+      decline_code,
+      doc_url: json["doc_url"].as_str().map(|r| r.to_string()),
       message: json["message"].as_str().unwrap_or("").to_string(),
       param: json["param"].as_str().unwrap_or("").to_string(),
+      request_id,
+      request_log_url: json["request_log_url"].as_str().map(|r| r.to_string()),
+      charge: json["charge"].as_str().map(|r| r.to_string()),
+      payment_method,
+      payment_method_type: json["payment_method_type"].as_str().map(|r| r.to_string()),
+      #[cfg(feature = "setup_intent")]
+      setup_intent,
       #[cfg(feature = "payment_intent")]
       payment_intent
     })
   }
-}
\ No newline at end of file
+}
+
+impl std::fmt::Display for Info {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} ({}) {}", self.http_code.original_u16(), self.r#type, self.code)?;
+    if let Some(r) = &self.decline_code {
+      write!(f, " / {}", r)?;
+    }
+    write!(f, ": {}", self.message)?;
+    if !self.param.is_empty() {
+      write!(f, " [param: {}]", self.param)?;
+    }
+    if let Some(r) = &self.request_id {
+      write!(f, " [request: {}]", r)?;
+    }
+
+    Ok(())
+  }
+}
+
+include!("classification.rs");
\ No newline at end of file
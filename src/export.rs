@@ -0,0 +1,133 @@
+use serde::Serialize;
+
+/// The format of an export.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+  /// Comma-separated values with a header row.
+  Csv,
+  /// One JSON object per line.
+  JsonLines
+}
+
+/// A flattened `crate::balance_transaction::Response` as it is written by `Client::export_balance_transactions()`.
+/// 
+/// The order of the fields is the order of the columns.
+#[derive(Serialize, Clone, PartialEq, Debug)]
+pub struct BalanceTransactionRow {
+  /// Unique identifier for the balance transaction.
+  pub id: String,
+  /// Transaction type, e.g. `charge` or `refund`.
+  pub r#type: String,
+  /// The [reporting category](https://stripe.com/docs/reports/reporting-categories) of the transaction.
+  pub reporting_category: String,
+  /// Time at which the transaction was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// The date the transaction’s net funds will become available in the Stripe balance.
+  pub available_on: i64,
+  /// Either `available` or `pending`.
+  pub status: String,
+  /// Three-letter ISO currency code, in lowercase.
+  pub currency: String,
+  /// Gross amount of the transaction, in cents.
+  pub amount: i64,
+  /// Fees (in cents) paid for this transaction.
+  pub fee: i64,
+  /// Net amount of the transaction, in cents.
+  pub net: i64,
+  /// Sum of all `fee_details` of type `stripe_fee`, in cents.
+  pub stripe_fee: i64,
+  /// Sum of all `fee_details` of type `application_fee`, in cents.
+  pub application_fee: i64,
+  /// Sum of all `fee_details` of type `tax`, in cents.
+  pub tax: i64,
+  /// Sum of all other `fee_details`, in cents.
+  pub other_fee: i64,
+  /// The exchange rate used, if applicable, for this transaction.
+  pub exchange_rate: Option<f64>,
+  /// The ID of the Stripe object to which this transaction is related.
+  pub source: Option<String>,
+  /// An arbitrary string attached to the transaction.
+  pub description: Option<String>
+}
+
+impl From<&crate::balance_transaction::Response> for BalanceTransactionRow {
+  fn from(value: &crate::balance_transaction::Response) -> Self {
+    let mut result = Self {
+      id: value.id.clone(),
+      r#type: value.r#type.clone(),
+      reporting_category: value.reporting_category.clone(),
+      created: value.created,
+      available_on: value.available_on,
+      status: value.status.clone(),
+      currency: value.currency.clone(),
+      amount: value.amount,
+      fee: value.fee,
+      net: value.net,
+      stripe_fee: 0,
+      application_fee: 0,
+      tax: 0,
+      other_fee: 0,
+      exchange_rate: value.exchange_rate,
+      source: value.source.as_ref().and_then(|r| r.id()).map(|r| r.to_string()),
+      description: value.description.clone()
+    };
+
+    for r in value.fee_details.iter() {
+      let amount = r.amount as i64;
+      match r.r#type.as_str() {
+        "stripe_fee" => result.stripe_fee += amount,
+        "application_fee" => result.application_fee += amount,
+        "tax" => result.tax += amount,
+        _ => result.other_fee += amount
+      }
+    }
+
+    result
+  }
+}
+
+/// Writes rows in the selected `Format` without keeping them in memory.
+pub(crate) enum Writer<W: std::io::Write> {
+  Csv(Box<csv::Writer<W>>),
+  JsonLines(W)
+}
+
+impl<W: std::io::Write> Writer<W> {
+  pub(crate) fn create(format: Format, writer: W) -> Self {
+    match format {
+      Format::Csv => Self::Csv(Box::new(csv::Writer::from_writer(writer))),
+      Format::JsonLines => Self::JsonLines(writer)
+    }
+  }
+
+  pub(crate) fn write<T: Serialize>(&mut self, row: &T) -> Result<(), String> {
+    let result = match self {
+      Self::Csv(r) => r.serialize(row).map_err(|e| e.to_string()),
+      Self::JsonLines(r) => serde_json::to_writer(&mut *r, row)
+        .map_err(|e| e.to_string())
+        .and_then(|_| r.write_all(b"\n").map_err(|e| e.to_string()))
+    };
+
+    result.map_err(|e| {
+      if log::log_enabled!(log::Level::Error) {
+        log::error!("{}", e);
+      }
+      "Row could not be written".to_string()
+    })
+  }
+
+  pub(crate) fn flush(&mut self) -> Result<(), String> {
+    let result = match self {
+      Self::Csv(r) => r.flush(),
+      Self::JsonLines(r) => r.flush()
+    };
+
+    result.map_err(|e| {
+      if log::log_enabled!(log::Level::Error) {
+        log::error!("{}", e);
+      }
+      "Writer could not be flushed".to_string()
+    })
+  }
+}
@@ -1,11 +1,12 @@
-/// Make a request with [reqwest](https://crates.io/crates/reqwest).
-pub(crate) async fn make_reqwest<T>(request: reqwest::RequestBuilder) -> Result<T, (String, Option<crate::error::Info>)> where T : serde::de::DeserializeOwned {
+/// Make a request with [reqwest](https://crates.io/crates/reqwest) and keep the `crate::meta::Meta` of the response.
+pub(crate) async fn make_reqwest_with_meta<T>(request: reqwest::RequestBuilder, lenient: bool) -> Result<crate::meta::Response<T>, (String, Option<crate::error::Info>)> where T : serde::de::DeserializeOwned {
   let response = match request.send().await {
       Ok(r) => r,
       Err(_) => return Err(("Request failed".to_string(), None))
     };
-  
+
   let status = response.status();
+  let mut meta = crate::meta::Meta::create(status.as_u16(), response.headers());
   let body_response = match response.text().await {
     Ok(r) => r,
     Err(e) => {
@@ -16,26 +17,268 @@ pub(crate) async fn make_reqwest<T>(request: reqwest::RequestBuilder) -> Result<
       return Err(("Body could not be unwrapped".to_string(), None));
     }
   };
-  
+
   if status.is_success() {
-    match serde_json::from_str::<T>(&body_response) {
-      Ok(r) => return Ok(r),
+    let decoded = if lenient {
+      decode_lenient::<T>(&body_response)
+    } else {
+      serde_json::from_str::<T>(&body_response).map(|r| (r, Vec::new()))
+    };
+
+    match decoded {
+      Ok((r, defaulted)) => {
+        for path in defaulted.iter() {
+          if log::log_enabled!(log::Level::Warn) {
+            log::warn!("`{}` could not be decoded and was replaced with its default value", path);
+          }
+        }
+        meta.defaulted = defaulted;
+        return Ok(crate::meta::Response { meta, body: r });
+      },
       Err(e) => {
         // This can be useful when we expected something like a string, but Stripe doesn't give us that value
         if log::log_enabled!(log::Level::Debug) {
           log::debug!("\n{}", &body_response);
         }
+        let request_id = meta.request_id.unwrap_or_default();
         if log::log_enabled!(log::Level::Error) {
           log::error!("Discovered errors! Send us this error so we can fix it (https://github.com/EntenKoeniq/ezstripe/issues)");
-          log::error!("{}", e);
+          log::error!("{} (Request-Id: {})", e, request_id);
         }
+        // Stripe support asks for the `Request-Id`, so it must not get lost with the `meta`
+        return Err((format!("Response could not be decoded (Request-Id: {})", request_id), None));
       }
     };
-  } else {
-    if let Some(r) = crate::error::Info::create(status.as_u16(), &body_response) {
-      return Err(("Status is not success".to_string(), Some(r)));
+  } else if let Some(r) = crate::error::Info::create(status.as_u16(), meta.request_id.clone(), &body_response) {
+    return Err(("Status is not success".to_string(), Some(r)));
+  }
+
+  Err(("Something went wrong".to_string(), None))
+}
+
+
+/// Make a request with [reqwest](https://crates.io/crates/reqwest) and return the undecoded bytes of the response.
+#[cfg(feature = "file")]
+pub(crate) async fn make_reqwest_bytes_with_meta(request: reqwest::RequestBuilder) -> Result<crate::meta::Response<Vec<u8>>, (String, Option<crate::error::Info>)> {
+  let response = match request.send().await {
+      Ok(r) => r,
+      Err(_) => return Err(("Request failed".to_string(), None))
+    };
+
+  let status = response.status();
+  let meta = crate::meta::Meta::create(status.as_u16(), response.headers());
+  let body_response = match response.bytes().await {
+    Ok(r) => r.to_vec(),
+    Err(e) => {
+      if log::log_enabled!(log::Level::Error) {
+        log::error!("Discovered errors! Send us this error so we can fix it (https://github.com/EntenKoeniq/ezstripe/issues)");
+        log::error!("{}", e);
+      }
+      return Err(("Body could not be unwrapped".to_string(), None));
     }
+  };
+
+  if status.is_success() {
+    return Ok(crate::meta::Response { meta, body: body_response });
+  } else if let Some(r) = crate::error::Info::create(status.as_u16(), meta.request_id.clone(), &String::from_utf8_lossy(&body_response)) {
+    return Err(("Status is not success".to_string(), Some(r)));
   }
-  
+
   Err(("Something went wrong".to_string(), None))
-}
\ No newline at end of file
+}
+
+/// The maximum number of values `decode_lenient` replaces before giving up.
+const LENIENT_MAX_ATTEMPTS: usize = 64;
+
+/// Decode `json_text` and replace every value that could not be decoded with a default value.
+/// 
+/// Only the value at the path of a decoding error is replaced, everything else (e.g. `extra`) is kept as it is.
+/// Values inside of an enumeration with a tag (e.g. `external_account::ExternalAccount`) cannot be replaced.
+/// Returns the decoded value and the paths of all replaced values, e.g. `data[0].description`.
+fn decode_lenient<T>(json_text: &str) -> Result<(T, Vec<String>), serde_json::Error> where T : serde::de::DeserializeOwned {
+  let mut json = serde_json::from_str::<serde_json::Value>(json_text)?;
+
+  let mut defaulted: Vec<Vec<PathSegment>> = Vec::new();
+  let mut result = decode_with_path::<T>(&json);
+  for _ in 0..LENIENT_MAX_ATTEMPTS {
+    let (path, message) = match &result {
+      Ok(_) => break,
+      Err((Some(path), e)) => (path.clone(), e.to_string()),
+      Err((None, _)) => break
+    };
+    if defaulted.contains(&path) {
+      break;
+    }
+
+    let mut solved = false;
+    for candidate in candidates(&json, &path, &message) {
+      let mut attempt = json.clone();
+      insert_at(&mut attempt, &path, candidate);
+
+      let attempt_result = decode_with_path::<T>(&attempt);
+      // Solved if the value at `path` is no longer the one that fails
+      solved = match &attempt_result {
+        Ok(_) => true,
+        Err((r, _)) => r.as_ref() != Some(&path)
+      };
+      if solved {
+        json = attempt;
+        result = attempt_result;
+        defaulted.push(path.clone());
+        break;
+      }
+    }
+
+    if !solved {
+      break;
+    }
+  }
+
+  result
+    .map(|r| (r, defaulted.iter().map(|r| path_to_string(r)).collect()))
+    .map_err(|(_, e)| e)
+}
+
+/// Decode `json` and return the path of the value that could not be decoded on failure.
+/// 
+/// The path is `None` if it contains a segment that cannot be repaired, e.g. a variant of an enumeration.
+fn decode_with_path<T>(json: &serde_json::Value) -> Result<T, (Option<Vec<PathSegment>>, serde_json::Error)> where T : serde::de::DeserializeOwned {
+  serde_path_to_error::deserialize::<_, T>(json).map_err(|e| {
+    let mut path = e.path()
+      .iter()
+      .map(|r| match r {
+        serde_path_to_error::Segment::Map { key } => Some(PathSegment::Key(key.clone())),
+        serde_path_to_error::Segment::Seq { index } => Some(PathSegment::Index(*index)),
+        _ => None
+      })
+      .collect::<Option<Vec<PathSegment>>>();
+
+    // A missing field is reported at the path of its parent
+    if let (Some(r), Some(field)) = (path.as_mut(), missing_field(&e.inner().to_string())) {
+      r.push(PathSegment::Key(field));
+    }
+
+    (path, e.into_inner())
+  })
+}
+
+/// The values tried in order to replace the value at `path`.
+/// 
+/// `null` comes first, so an `Option` becomes `None` instead of a made up value.
+fn candidates(json: &serde_json::Value, path: &[PathSegment], message: &str) -> Vec<serde_json::Value> {
+  // The error of an object or array is located inside of it (e.g. an enumeration with a tag), so we cannot replace it
+  if matches!(get(json, path), Some(serde_json::Value::Object(_)) | Some(serde_json::Value::Array(_))) {
+    return Vec::new();
+  }
+
+  let mut result = vec![
+    serde_json::Value::Null,
+    serde_json::Value::String(String::new()),
+    serde_json::Value::from(0),
+    serde_json::Value::Bool(false),
+    serde_json::Value::Object(serde_json::Map::new()),
+    serde_json::Value::Array(Vec::new())
+  ];
+
+  // e.g. "unknown variant `x`, expected one of `a`, `b`"
+  if let Some((r, _)) = message.split_once("expected one of `").and_then(|(_, r)| r.split_once('`')) {
+    result.push(serde_json::Value::String(r.to_string()));
+  }
+
+  result
+}
+
+#[derive(Clone, PartialEq)]
+enum PathSegment {
+  Key(String),
+  Index(usize)
+}
+
+fn path_to_string(path: &[PathSegment]) -> String {
+  let mut result = String::new();
+  for segment in path {
+    match segment {
+      PathSegment::Key(k) => {
+        if !result.is_empty() {
+          result.push('.');
+        }
+        result += k;
+      },
+      PathSegment::Index(i) => result += &format!("[{}]", i)
+    }
+  }
+
+  result
+}
+
+/// Extracts the field name of a serde "missing field" error.
+fn missing_field(message: &str) -> Option<String> {
+  let rest = message.strip_prefix("missing field `")?;
+  Some(rest[..rest.find('`')?].to_string())
+}
+
+fn get<'a>(json: &'a serde_json::Value, path: &[PathSegment]) -> Option<&'a serde_json::Value> {
+  let mut current = json;
+  for segment in path {
+    current = match segment {
+      PathSegment::Key(k) => current.as_object()?.get(k)?,
+      PathSegment::Index(i) => current.as_array()?.get(*i)?
+    };
+  }
+
+  Some(current)
+}
+
+fn get_mut<'a>(json: &'a mut serde_json::Value, path: &[PathSegment]) -> Option<&'a mut serde_json::Value> {
+  let mut current = json;
+  for segment in path {
+    current = match segment {
+      PathSegment::Key(k) => current.as_object_mut()?.get_mut(k)?,
+      PathSegment::Index(i) => current.as_array_mut()?.get_mut(*i)?
+    };
+  }
+
+  Some(current)
+}
+
+fn insert_at(json: &mut serde_json::Value, path: &[PathSegment], value: serde_json::Value) {
+  match path.split_last() {
+    Some((PathSegment::Key(k), parent)) => {
+      if let Some(serde_json::Value::Object(map)) = get_mut(json, parent) {
+        map.insert(k.clone(), value);
+      }
+    },
+    Some((PathSegment::Index(_), _)) => {
+      if let Some(r) = get_mut(json, path) {
+        *r = value;
+      }
+    },
+    None => ()
+  }
+}
+
+/// Percent-encode `input` so it can be used as a value in the body of a request.
+#[cfg(any(
+  feature = "balance_transaction",
+  feature = "charge",
+  feature = "dispute",
+  feature = "invoice",
+  feature = "payment_intent",
+  feature = "payment_link",
+  feature = "payout",
+  feature = "price",
+  feature = "product",
+  feature = "refund",
+  feature = "subscription"
+))]
+pub(crate) fn encode(input: &str) -> String {
+  let mut result = String::with_capacity(input.len());
+  for b in input.bytes() {
+    match b {
+      b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => result.push(b as char),
+      _ => result += &format!("%{:02X}", b)
+    }
+  }
+
+  result
+}
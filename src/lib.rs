@@ -1,19 +1,85 @@
+#![allow(clippy::upper_case_acronyms)]
+
 pub(crate) mod helper;
+#[cfg(feature = "account")]
+pub mod account;
+#[cfg(feature = "account")]
+pub mod account_link;
+#[cfg(feature = "application_fee")]
+pub mod application_fee;
+#[cfg(feature = "application_fee")]
+pub mod application_fee_refund;
 #[cfg(feature = "balance")]
 pub mod balance;
 #[cfg(feature = "balance_transaction")]
 pub mod balance_transaction;
+#[cfg(feature = "account")]
+pub mod capability;
+#[cfg(feature = "customer_balance")]
+pub mod cash_balance;
+#[cfg(feature = "customer_balance")]
+pub mod cash_balance_transaction;
+#[cfg(feature = "charge")]
+pub mod charge;
+#[cfg(feature = "checkout")]
+pub mod checkout;
+#[cfg(feature = "customer_balance")]
+pub mod customer_balance_transaction;
 #[cfg(feature = "dispute")]
 pub mod dispute;
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(feature = "account")]
+pub mod external_account;
+#[cfg(feature = "file")]
+pub mod file;
+#[cfg(feature = "file")]
+pub mod file_link;
+#[cfg(feature = "invoice")]
+pub mod invoice;
+#[cfg(feature = "invoice_item")]
+pub mod invoice_item;
+#[cfg(feature = "account")]
+pub mod login_link;
 #[cfg(feature = "mandate")]
 pub mod mandate;
 #[cfg(feature = "payment_intent")]
 pub mod payment_intent;
+#[cfg(feature = "payment_link")]
+pub mod payment_link;
 #[cfg(feature = "payout")]
 pub mod payout;
+#[cfg(feature = "account")]
+pub mod person;
+#[cfg(feature = "price")]
+pub mod price;
+#[cfg(feature = "product")]
+pub mod product;
 #[cfg(feature = "refund")]
 pub mod refund;
+#[cfg(feature = "reporting")]
+pub mod report_run;
+#[cfg(feature = "reporting")]
+pub mod report_type;
+#[cfg(feature = "setup_intent")]
+pub mod setup_intent;
+#[cfg(feature = "subscription")]
+pub mod subscription;
+#[cfg(feature = "subscription_item")]
+pub mod subscription_item;
+#[cfg(feature = "transfer")]
+pub mod transfer;
+#[cfg(feature = "transfer")]
+pub mod transfer_reversal;
+#[cfg(feature = "usage_record")]
+pub mod usage_record;
 pub mod error;
+pub mod meta;
+
+/// All fields Stripe returned that are not (yet) part of a struct.
+/// 
+/// Every struct decoded from a response keeps them in its `extra` field instead of dropping them.
+pub type Extra = serde_json::Map<String, serde_json::Value>;
 
 include!("client.rs");
 
@@ -5,17 +5,30 @@ include!("split/structs/mandate/response.rs");
 pub struct Info<'a> {
   pub(crate) secret_key: String,
   pub(crate) reqwest_client: &'a reqwest::Client,
+  pub(crate) lenient: bool,
   pub(crate) id: String
 }
 
 impl Info<'_> {
   /// Sends a "GET" request to Stripe's API.
   pub async fn get(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
-    let crequest = self.reqwest_client
+    self.get_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
+    crate::helper::make_reqwest_with_meta::<Response>(self._create_get_request(), self.lenient).await
+  }
+
+  /// Sends a "GET" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn get_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self._create_get_request(), false).await
+  }
+
+  fn _create_get_request(&self) -> reqwest::RequestBuilder {
+    self.reqwest_client
       .get(format!("https://api.stripe.com/v1/mandates/{}", self.id))
       .basic_auth(&self.secret_key, None::<&str>)
-      .header("Content-Type", "application/x-www-form-urlencoded");
-    
-    crate::helper::make_reqwest::<Response>(crequest).await
+      .header("Content-Type", "application/x-www-form-urlencoded")
   }
 }
\ No newline at end of file
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+/// Additional information about a response from Stripe's API.
+#[derive(Clone, Debug)]
+pub struct Meta {
+  /// The HTTP response status code.
+  pub status: u16,
+  /// The unique identifier of the request (`Request-Id` header).
+  /// Stripe support asks for this value when you report a problem with a request.
+  pub request_id: Option<String>,
+  /// The idempotency key Stripe used for the request (`Idempotency-Key` header).
+  pub idempotency_key: Option<String>,
+  /// The API version used to process the request (`Stripe-Version` header).
+  pub stripe_version: Option<String>,
+  /// All response headers with lowercase names, including rate-limit headers.
+  pub headers: HashMap<String, String>,
+  /// Paths of all values that could not be decoded and were replaced with their default value.
+  /// Always empty unless `Client::lenient` is enabled.
+  pub defaulted: Vec<String>
+}
+
+impl Meta {
+  pub(crate) fn create(status: u16, headers: &reqwest::header::HeaderMap) -> Self {
+    let headers: HashMap<String, String> = headers
+      .iter()
+      .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.as_str().to_string(), v.to_string())))
+      .collect();
+
+    Self {
+      status,
+      request_id: headers.get("request-id").cloned(),
+      idempotency_key: headers.get("idempotency-key").cloned(),
+      stripe_version: headers.get("stripe-version").cloned(),
+      headers,
+      defaulted: Vec::new()
+    }
+  }
+
+  /// Returns the value of the header with the given `name` (case-insensitive).
+  pub fn header(&self, name: &str) -> Option<&str> {
+    self.headers.get(&name.to_lowercase()).map(|r| r.as_str())
+  }
+
+  /// Returns the HTTP response status as `crate::error::HTTPCodes`.
+  pub fn http_code(&self) -> crate::error::HTTPCodes {
+    crate::error::HTTPCodes::from_status(self.status)
+  }
+}
+
+/// A decoded response from Stripe's API together with its `Meta`.
+#[derive(Clone, Debug)]
+pub struct Response<T> {
+  /// Additional information about the response, e.g. the `Request-Id`.
+  pub meta: Meta,
+  /// The decoded body of the response.
+  pub body: T
+}
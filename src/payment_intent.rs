@@ -6,6 +6,9 @@ include!("split/structs/payment_intent/response.rs");
 
 include!("split/structs/payment_intent/response_list.rs");
 
+include!("split/structs/payment_intent/response_search.rs");
+
+#[allow(non_camel_case_types)]
 #[derive(PartialEq)]
 pub(crate) enum Types {
   CREATE(String),
@@ -13,8 +16,12 @@ pub(crate) enum Types {
   CONFIRM(String, String),
   CANCEL(String, String),
   UPDATE(String, String),
-  CAPTURE(String),
-  LIST(String)
+  CAPTURE(String, String),
+  INCREMENT_AUTHORIZATION(String, String),
+  APPLY_CUSTOMER_BALANCE(String, String),
+  VERIFY_MICRODEPOSITS(String, String),
+  LIST(String),
+  SEARCH(String)
 }
 
 const PAYMENT_INTENT_URL: &str = "https://api.stripe.com/v1/payment_intents";
@@ -48,13 +55,17 @@ impl Types {
 
   fn _get_url(&self) -> String {
     match self {
-      Self::CREATE(_) => format!("{}", PAYMENT_INTENT_URL),
+      Self::CREATE(_) => PAYMENT_INTENT_URL.to_string(),
       Self::RETRIEVE(id) => format!("{}/{}", PAYMENT_INTENT_URL, id),
       Self::CONFIRM(id, _) => format!("{}/{}/confirm", PAYMENT_INTENT_URL, id),
       Self::CANCEL(id, _) => format!("{}/{}/cancel", PAYMENT_INTENT_URL, id),
       Self::UPDATE(id, _) => format!("{}/{}", PAYMENT_INTENT_URL, id),
-      Self::CAPTURE(id) => format!("{}/{}/capture", PAYMENT_INTENT_URL, id),
-      Self::LIST(_) => format!("{}", PAYMENT_INTENT_URL)
+      Self::CAPTURE(id, _) => format!("{}/{}/capture", PAYMENT_INTENT_URL, id),
+      Self::INCREMENT_AUTHORIZATION(id, _) => format!("{}/{}/increment_authorization", PAYMENT_INTENT_URL, id),
+      Self::APPLY_CUSTOMER_BALANCE(id, _) => format!("{}/{}/apply_customer_balance", PAYMENT_INTENT_URL, id),
+      Self::VERIFY_MICRODEPOSITS(id, _) => format!("{}/{}/verify_microdeposits", PAYMENT_INTENT_URL, id),
+      Self::LIST(_) => PAYMENT_INTENT_URL.to_string(),
+      Self::SEARCH(_) => format!("{}/search", PAYMENT_INTENT_URL)
     }
   }
 
@@ -64,7 +75,12 @@ impl Types {
       Self::CONFIRM(_, body) => body,
       Self::CANCEL(_, body) => body,
       Self::UPDATE(_, body) => body,
+      Self::CAPTURE(_, body) => body,
+      Self::INCREMENT_AUTHORIZATION(_, body) => body,
+      Self::APPLY_CUSTOMER_BALANCE(_, body) => body,
+      Self::VERIFY_MICRODEPOSITS(_, body) => body,
       Self::LIST(body) => body,
+      Self::SEARCH(body) => body,
       _ => ""
     };
 
@@ -79,12 +95,18 @@ impl Types {
 pub struct Info<'a> {
   pub(crate) r#type: Types,
   pub(crate) secret_key: String,
-  pub(crate) reqwest_client: &'a reqwest::Client
+  pub(crate) reqwest_client: &'a reqwest::Client,
+  pub(crate) lenient: bool
 }
 
 impl Info<'_> {
   /// Sends a "POST" request to Stripe's API.
   pub async fn send(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.send_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "POST" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn send_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::RETRIEVE(_) => {
         if log::log_enabled!(log::Level::Error) {
@@ -98,14 +120,25 @@ impl Info<'_> {
         }
         return Err(("This function is not compatible with the selected type".to_string(), None));
       },
+      Types::SEARCH(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send()`. Please use the `get_search()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
       _ => ()
     };
 
-    crate::helper::make_reqwest::<Response>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key)).await
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), self.lenient).await
   }
-  
+
   /// Sends a "GET" request to Stripe's API.
   pub async fn get(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.get_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::RETRIEVE(_) => (),
       Types::LIST(_) => {
@@ -114,6 +147,12 @@ impl Info<'_> {
         }
         return Err(("This function is not compatible with the selected type".to_string(), None));
       },
+      Types::SEARCH(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get()`. Please use the `get_search()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
       _ => {
         if log::log_enabled!(log::Level::Error) {
           log::error!("The selected type is not compatible with `get()`. Please use the `send()` function");
@@ -121,12 +160,17 @@ impl Info<'_> {
         return Err(("This function is not compatible with the selected type".to_string(), None));
       }
     };
-    
-    crate::helper::make_reqwest::<Response>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key)).await
+
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
   }
 
   /// Sends a "GET" request to Stripe's API.
   pub async fn get_list(&self) -> Result<ResponseList, (String, Option<crate::error::Info>)> {
+    self.get_list_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_list_with_meta(&self) -> Result<crate::meta::Response<ResponseList>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::LIST(_) => (),
       Types::RETRIEVE(_) => {
@@ -135,6 +179,12 @@ impl Info<'_> {
         }
         return Err(("This function is not compatible with the selected type".to_string(), None));
       },
+      Types::SEARCH(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_list()`. Please use the `get_search()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
       _ => {
         if log::log_enabled!(log::Level::Error) {
           log::error!("The selected type is not compatible with `get_list()`. Please use the `send()` function");
@@ -142,7 +192,69 @@ impl Info<'_> {
         return Err(("This function is not compatible with the selected type".to_string(), None));
       }
     };
-    
-    crate::helper::make_reqwest::<ResponseList>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key)).await
+
+    crate::helper::make_reqwest_with_meta::<ResponseList>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "GET" request to Stripe's API.
+  pub async fn get_search(&self) -> Result<ResponseSearch, (String, Option<crate::error::Info>)> {
+    self.get_search_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_search_with_meta(&self) -> Result<crate::meta::Response<ResponseSearch>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::SEARCH(_) => (),
+      Types::RETRIEVE(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_search()`. Please use the `get()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::LIST(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_search()`. Please use the `get_list()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      _ => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_search()`. Please use the `send()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      }
+    };
+
+    crate::helper::make_reqwest_with_meta::<ResponseSearch>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "POST" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn send_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) | Types::LIST(_) | Types::SEARCH(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send_raw()`. Please use the `get_raw()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      _ => ()
+    };
+
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), false).await
+  }
+
+  /// Sends a "GET" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn get_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) | Types::LIST(_) | Types::SEARCH(_) => (),
+      _ => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_raw()`. Please use the `send_raw()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      }
+    };
+
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), false).await
   }
 }
\ No newline at end of file
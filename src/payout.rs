@@ -6,9 +6,11 @@ include!("split/structs/payout/response.rs");
 
 include!("split/structs/payout/response_list.rs");
 
+include!("split/structs/payout/parameters.rs");
+
 #[derive(PartialEq)]
 pub(crate) enum Types {
-  CREATE(String),
+  CREATE(PayoutParams),
   RETRIEVE(String),
   UPDATE(String, String),
   LIST(String),
@@ -47,10 +49,10 @@ impl Types {
 
   fn _get_url(&self) -> String {
     match self {
-      Self::CREATE(_) => format!("{}", PAYOUT_URL),
+      Self::CREATE(_) => PAYOUT_URL.to_string(),
       Self::RETRIEVE(id) => format!("{}/{}", PAYOUT_URL, id),
       Self::UPDATE(id, _) => format!("{}/{}", PAYOUT_URL, id),
-      Self::LIST(_) => format!("{}", PAYOUT_URL),
+      Self::LIST(_) => PAYOUT_URL.to_string(),
       Self::CANCEL(id) => format!("{}/{}/cancel", PAYOUT_URL, id),
       Self::REVERSE(id, _) => format!("{}/{}/reverse", PAYOUT_URL, id)
     }
@@ -58,17 +60,17 @@ impl Types {
 
   fn _get_body(&self) -> Option<String> {
     let body = match self {
-      Self::CREATE(body) => body,
-      Self::UPDATE(_, body) => body,
-      Self::LIST(body) => body,
-      Self::REVERSE(_, body) => body,
-      _ => ""
+      Self::CREATE(params) => params.to_string(),
+      Self::UPDATE(_, body) => body.to_string(),
+      Self::LIST(body) => body.to_string(),
+      Self::REVERSE(_, body) => body.to_string(),
+      _ => String::new()
     };
 
     if body.is_empty() {
       None
     } else {
-      Some(body.to_string())
+      Some(body)
     }
   }
 }
@@ -76,12 +78,18 @@ impl Types {
 pub struct Info<'a> {
   pub(crate) r#type: Types,
   pub(crate) secret_key: String,
-  pub(crate) reqwest_client: &'a reqwest::Client
+  pub(crate) reqwest_client: &'a reqwest::Client,
+  pub(crate) lenient: bool
 }
 
 impl Info<'_> {
   /// Sends a "POST" request to Stripe's API.
   pub async fn send(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.send_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "POST" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn send_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::RETRIEVE(_) => {
         if log::log_enabled!(log::Level::Error) {
@@ -95,14 +103,20 @@ impl Info<'_> {
         }
         return Err(("This function is not compatible with the selected type".to_string(), None));
       },
+      Types::CREATE(ref params) => params.validate().map_err(|r| (r, None))?,
       _ => ()
     };
 
-    crate::helper::make_reqwest::<Response>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key)).await
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), self.lenient).await
   }
   
   /// Sends a "GET" request to Stripe's API.
   pub async fn get(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.get_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::RETRIEVE(_) => (),
       Types::LIST(_) => {
@@ -119,11 +133,16 @@ impl Info<'_> {
       }
     };
     
-    crate::helper::make_reqwest::<Response>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key)).await
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
   }
 
   /// Sends a "GET" request to Stripe's API.
   pub async fn get_list(&self) -> Result<ResponseList, (String, Option<crate::error::Info>)> {
+    self.get_list_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_list_with_meta(&self) -> Result<crate::meta::Response<ResponseList>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::LIST(_) => (),
       Types::RETRIEVE(_) => {
@@ -140,6 +159,37 @@ impl Info<'_> {
       }
     };
     
-    crate::helper::make_reqwest::<ResponseList>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key)).await
+    crate::helper::make_reqwest_with_meta::<ResponseList>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "POST" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn send_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) | Types::LIST(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send_raw()`. Please use the `get_raw()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::CREATE(ref params) => params.validate().map_err(|r| (r, None))?,
+      _ => ()
+    };
+
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), false).await
+  }
+
+  /// Sends a "GET" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn get_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) | Types::LIST(_) => (),
+      _ => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_raw()`. Please use the `send_raw()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      }
+    };
+
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), false).await
   }
 }
\ No newline at end of file
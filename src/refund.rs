@@ -6,14 +6,15 @@ include!("split/structs/refund/response.rs");
 
 include!("split/structs/refund/response_list.rs");
 
+include!("split/structs/refund/parameters.rs");
+
 #[derive(PartialEq)]
 pub(crate) enum Types {
-  CREATE(String),
+  CREATE(RefundParams),
   RETRIEVE(String),
   UPDATE(String, String),
   LIST(String),
-  CANCEL(String),
-  REVERSE(String, String)
+  CANCEL(String)
 }
 
 const REFUND_URL: &str = "https://api.stripe.com/v1/refunds";
@@ -48,28 +49,26 @@ impl Types {
 
   fn _get_url(&self) -> String {
     match self {
-      Self::CREATE(_) => format!("{}", REFUND_URL),
+      Self::CREATE(_) => REFUND_URL.to_string(),
       Self::RETRIEVE(id) => format!("{}/{}", REFUND_URL, id),
       Self::UPDATE(id, _) => format!("{}/{}", REFUND_URL, id),
-      Self::LIST(_) => format!("{}", REFUND_URL),
-      Self::CANCEL(id) => format!("{}/{}/cancel", REFUND_URL, id),
-      Self::REVERSE(id, _) => format!("{}/{}/reverse", REFUND_URL, id)
+      Self::LIST(_) => REFUND_URL.to_string(),
+      Self::CANCEL(id) => format!("{}/{}/cancel", REFUND_URL, id)
     }
   }
 
   fn _get_body(&self) -> Option<String> {
     let body = match self {
-      Self::CREATE(body) => body,
-      Self::UPDATE(_, body) => body,
-      Self::LIST(body) => body,
-      Self::REVERSE(_, body) => body,
-      _ => ""
+      Self::CREATE(params) => params.to_string(),
+      Self::UPDATE(_, body) => body.to_string(),
+      Self::LIST(body) => body.to_string(),
+      _ => String::new()
     };
 
     if body.is_empty() {
       None
     } else {
-      Some(body.to_string())
+      Some(body)
     }
   }
 }
@@ -78,12 +77,18 @@ impl Types {
 pub struct Info<'a> {
   pub(crate) r#type: Types,
   pub(crate) secret_key: String,
-  pub(crate) reqwest_client: &'a reqwest::Client
+  pub(crate) reqwest_client: &'a reqwest::Client,
+  pub(crate) lenient: bool
 }
 
 impl Info<'_> {
   /// Sends a "POST" request to Stripe's API.
   pub async fn send(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.send_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "POST" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn send_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::RETRIEVE(_) => {
         if log::log_enabled!(log::Level::Error) {
@@ -97,14 +102,20 @@ impl Info<'_> {
         }
         return Err(("This function is not compatible with the selected type".to_string(), None));
       },
+      Types::CREATE(ref params) => params.validate().map_err(|r| (r, None))?,
       _ => ()
     };
 
-    crate::helper::make_reqwest::<Response>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key)).await
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), self.lenient).await
   }
 
   /// Sends a "GET" request to Stripe's API.
   pub async fn get(&self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.get_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_with_meta(&self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::RETRIEVE(_) => (),
       Types::LIST(_) => {
@@ -121,11 +132,16 @@ impl Info<'_> {
       }
     };
     
-    crate::helper::make_reqwest::<Response>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key)).await
+    crate::helper::make_reqwest_with_meta::<Response>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
   }
 
   /// Sends a "GET" request to Stripe's API.
   pub async fn get_list(&self) -> Result<ResponseList, (String, Option<crate::error::Info>)> {
+    self.get_list_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "GET" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn get_list_with_meta(&self) -> Result<crate::meta::Response<ResponseList>, (String, Option<crate::error::Info>)> {
     match self.r#type {
       Types::LIST(_) => (),
       Types::RETRIEVE(_) => {
@@ -142,6 +158,37 @@ impl Info<'_> {
       }
     };
     
-    crate::helper::make_reqwest::<ResponseList>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key)).await
+    crate::helper::make_reqwest_with_meta::<ResponseList>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), self.lenient).await
+  }
+
+  /// Sends a "POST" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn send_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) | Types::LIST(_) => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `send_raw()`. Please use the `get_raw()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      },
+      Types::CREATE(ref params) => params.validate().map_err(|r| (r, None))?,
+      _ => ()
+    };
+
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_send_request(self.reqwest_client, &self.secret_key), false).await
+  }
+
+  /// Sends a "GET" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn get_raw(&self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    match self.r#type {
+      Types::RETRIEVE(_) | Types::LIST(_) => (),
+      _ => {
+        if log::log_enabled!(log::Level::Error) {
+          log::error!("The selected type is not compatible with `get_raw()`. Please use the `send_raw()` function");
+        }
+        return Err(("This function is not compatible with the selected type".to_string(), None));
+      }
+    };
+
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(self.r#type.create_get_request(self.reqwest_client, &self.secret_key), false).await
   }
 }
\ No newline at end of file
@@ -0,0 +1,156 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[macro_use] extern crate ezstripe;
+  /// 
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   // Returns: String("type=express;country=DE;email=jenny.rosen@example.com;")
+  ///   let stripe_body = ezbody!(
+  ///       "type" => "express",
+  ///       "country" => "DE",
+  ///       "email" => "jenny.rosen@example.com"
+  ///     );
+  ///   
+  ///   let stripe_response = client.create_account(stripe_body).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_account(&self, body: String) -> crate::account::Info<'_> {
+    crate::account::Info {
+      r#type: crate::account::Types::CREATE(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_account("ACCOUNT_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_account(&self, id: String) -> crate::account::Info<'_> {
+    crate::account::Info {
+      r#type: crate::account::Types::RETRIEVE(id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Business profile
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_account("ACCOUNT_ID".to_string(), "metadata[order_id]=6735;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_account(&self, id: String, body: String) -> crate::account::Info<'_> {
+    crate::account::Info {
+      r#type: crate::account::Types::UPDATE(id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.delete_account("ACCOUNT_ID".to_string()).delete().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn delete_account(&self, id: String) -> crate::account::Info<'_> {
+    crate::account::Info {
+      r#type: crate::account::Types::DELETE(id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Reason (`fraud`, `terms_of_service` or `other`)
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.reject_account("ACCOUNT_ID".to_string(), "reason=fraud;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn reject_account(&self, id: String, body: String) -> crate::account::Info<'_> {
+    crate::account::Info {
+      r#type: crate::account::Types::REJECT(id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_account("limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_account(&self, body: String) -> crate::account::Info<'_> {
+    crate::account::Info {
+      r#type: crate::account::Types::LIST(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Account and type
+  /// 
+  /// # Example
+  /// ```
+  /// #[macro_use] extern crate ezstripe;
+  /// 
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   // Returns: String("account=ACCOUNT_ID;refresh_url=https://example.com/reauth;return_url=https://example.com/return;type=account_onboarding;")
+  ///   let stripe_body = ezbody!(
+  ///       "account" => "ACCOUNT_ID",
+  ///       "refresh_url" => "https://example.com/reauth",
+  ///       "return_url" => "https://example.com/return",
+  ///       "type" => "account_onboarding"
+  ///     );
+  ///   
+  ///   let stripe_response = client.create_account_link(stripe_body).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_account_link(&self, body: String) -> crate::account_link::Info<'_> {
+    crate::account_link::Info {
+      r#type: crate::account_link::Types::CREATE(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
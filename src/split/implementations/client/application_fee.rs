@@ -0,0 +1,49 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID of the application fee
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_application_fee("APPLICATION_FEE_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_application_fee(&self, id: String) -> crate::application_fee::Info<'_> {
+    crate::application_fee::Info {
+      r#type: crate::application_fee::Types::RETRIEVE(id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Charge
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_application_fee("limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_application_fee(&self, body: String) -> crate::application_fee::Info<'_> {
+    crate::application_fee::Info {
+      r#type: crate::application_fee::Types::LIST(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `fee` - The unique ID of the application fee
+  /// * `body` - The content that provides details for Stripe, e.g. B. Amount
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.create_application_fee_refund("APPLICATION_FEE_ID".to_string(), "amount=100;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_application_fee_refund(&self, fee: String, body: String) -> crate::application_fee_refund::Info<'_> {
+    crate::application_fee_refund::Info {
+      r#type: crate::application_fee_refund::Types::CREATE(fee, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `fee` - The unique ID of the application fee
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_application_fee_refund("APPLICATION_FEE_ID".to_string(), "FEE_REFUND_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_application_fee_refund(&self, fee: String, id: String) -> crate::application_fee_refund::Info<'_> {
+    crate::application_fee_refund::Info {
+      r#type: crate::application_fee_refund::Types::RETRIEVE(fee, id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `fee` - The unique ID of the application fee
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Metadata
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_application_fee_refund("APPLICATION_FEE_ID".to_string(), "FEE_REFUND_ID".to_string(), "metadata[order_id]=6735;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_application_fee_refund(&self, fee: String, id: String, body: String) -> crate::application_fee_refund::Info<'_> {
+    crate::application_fee_refund::Info {
+      r#type: crate::application_fee_refund::Types::UPDATE(fee, id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `fee` - The unique ID of the application fee
+  /// * `body` - The content that provides details for Stripe, e.g. B. Limit
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_application_fee_refund("APPLICATION_FEE_ID".to_string(), "limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_application_fee_refund(&self, fee: String, body: String) -> crate::application_fee_refund::Info<'_> {
+    crate::application_fee_refund::Info {
+      r#type: crate::application_fee_refund::Types::LIST(fee, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
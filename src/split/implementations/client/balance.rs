@@ -10,10 +10,11 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn retrieve_balance(&self) -> crate::balance::Info {
+  pub fn retrieve_balance(&self) -> crate::balance::Info<'_> {
     crate::balance::Info {
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 }
\ No newline at end of file
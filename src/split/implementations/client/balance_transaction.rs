@@ -10,11 +10,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn retrieve_balance_transaction(&self, id: String) -> crate::balance_transaction::Info {
+  pub fn retrieve_balance_transaction(&self, id: String) -> crate::balance_transaction::Info<'_> {
     crate::balance_transaction::Info {
       r#type: crate::balance_transaction::Types::RETRIEVE(id),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -29,11 +30,51 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn list_balance_transaction(&self, body: String) -> crate::balance_transaction::Info {
+  pub fn list_balance_transaction(&self, body: String) -> crate::balance_transaction::Info<'_> {
     crate::balance_transaction::Info {
       r#type: crate::balance_transaction::Types::LIST(body),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
+
+  /// Sends "GET" requests to Stripe's API until all balance transactions of a payout have been received and groups them by `reporting_category`.
+  /// 
+  /// The `source` of every balance transaction is expanded.
+  /// 
+  /// # Arguments
+  /// 
+  /// * `payout_id` - The unique ID of an automatic payout
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(r) = client.payout_reconciliation("PAYOUT_ID".to_string()).await {
+  ///     for category in r.categories.iter() {
+  ///       println!("{}: {:?}", category.reporting_category, category.totals);
+  ///     }
+  ///     println!("Net: {}", r.totals.net);
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub async fn payout_reconciliation(&self, payout_id: String) -> Result<crate::balance_transaction::Reconciliation, (String, Option<crate::error::Info>)> {
+    let mut result: Vec<crate::balance_transaction::Response> = Vec::new();
+    loop {
+      let starting_after = result.last().map(|r| format!("starting_after={};", r.id)).unwrap_or_default();
+      let page = self.list_balance_transaction(format!("payout={};expand[]=data.source;limit=100;{}", crate::helper::encode(&payout_id), starting_after)).get_list().await?;
+      let has_more = page.has_more && !page.data.is_empty();
+      result.extend(page.data);
+      if !has_more {
+        break;
+      }
+    }
+
+    Ok(crate::balance_transaction::Reconciliation::create(payout_id, result))
+  }
 }
\ No newline at end of file
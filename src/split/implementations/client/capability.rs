@@ -0,0 +1,77 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the connected account
+  /// * `id` - The identifier of the capability, e.g. `card_payments`
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_capability("ACCOUNT_ID".to_string(), "card_payments".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_capability(&self, account: String, id: String) -> crate::capability::Info<'_> {
+    crate::capability::Info {
+      r#type: crate::capability::Types::RETRIEVE(account, id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the connected account
+  /// * `id` - The identifier of the capability, e.g. `card_payments`
+  /// * `body` - The content that provides details for Stripe, e.g. B. Requested
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_capability("ACCOUNT_ID".to_string(), "card_payments".to_string(), "requested=true;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_capability(&self, account: String, id: String, body: String) -> crate::capability::Info<'_> {
+    crate::capability::Info {
+      r#type: crate::capability::Types::UPDATE(account, id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the connected account
+  /// * `body` - The content that provides details for Stripe, e.g. B. Expand
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_capability("ACCOUNT_ID".to_string(), String::new()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_capability(&self, account: String, body: String) -> crate::capability::Info<'_> {
+    crate::capability::Info {
+      r#type: crate::capability::Types::LIST(account, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `customer` - The unique ID of the customer
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_cash_balance("CUSTOMER_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_cash_balance(&self, customer: String) -> crate::cash_balance::Info<'_> {
+    crate::cash_balance::Info {
+      r#type: crate::cash_balance::Types::RETRIEVE(customer),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `customer` - The unique ID of the customer
+  /// * `body` - The content that provides details for Stripe, e.g. B. Settings
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_cash_balance("CUSTOMER_ID".to_string(), "settings[reconciliation_mode]=manual;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_cash_balance(&self, customer: String, body: String) -> crate::cash_balance::Info<'_> {
+    crate::cash_balance::Info {
+      r#type: crate::cash_balance::Types::UPDATE(customer, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
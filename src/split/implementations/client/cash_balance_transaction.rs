@@ -0,0 +1,51 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `customer` - The unique ID of the customer
+  /// * `id` - The unique ID of the cash balance transaction
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_cash_balance_transaction("CUSTOMER_ID".to_string(), "TRANSACTION_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_cash_balance_transaction(&self, customer: String, id: String) -> crate::cash_balance_transaction::Info<'_> {
+    crate::cash_balance_transaction::Info {
+      r#type: crate::cash_balance_transaction::Types::RETRIEVE(customer, id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `customer` - The unique ID of the customer
+  /// * `body` - The content that provides details for Stripe, e.g. B. Limit
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_cash_balance_transaction("CUSTOMER_ID".to_string(), "limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_cash_balance_transaction(&self, customer: String, body: String) -> crate::cash_balance_transaction::Info<'_> {
+    crate::cash_balance_transaction::Info {
+      r#type: crate::cash_balance_transaction::Types::LIST(customer, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
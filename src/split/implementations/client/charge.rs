@@ -0,0 +1,157 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[macro_use] extern crate ezstripe;
+  /// 
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   // Returns: String("amount=2000;currency=eur;source=tok_visa;")
+  ///   let stripe_body = ezbody!(
+  ///       "amount" => 2000,
+  ///       "currency" => "eur",
+  ///       "source" => "tok_visa"
+  ///     );
+  ///   
+  ///   let stripe_response = client.create_charge(stripe_body).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_charge(&self, body: String) -> crate::charge::Info<'_> {
+    crate::charge::Info {
+      r#type: crate::charge::Types::CREATE(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_charge("CHARGE_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_charge(&self, id: String) -> crate::charge::Info<'_> {
+    crate::charge::Info {
+      r#type: crate::charge::Types::RETRIEVE(id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_charge("CHARGE_ID".to_string(), "metadata[order_id]=2;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_charge(&self, id: String, body: String) -> crate::charge::Info<'_> {
+    crate::charge::Info {
+      r#type: crate::charge::Types::UPDATE(id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Amount
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.capture_charge("CHARGE_ID".to_string(), None).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn capture_charge(&self, id: String, body: Option<String>) -> crate::charge::Info<'_> {
+    crate::charge::Info {
+      r#type: crate::charge::Types::CAPTURE(id, body.unwrap_or_default()),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_charge("limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_charge(&self, body: String) -> crate::charge::Info<'_> {
+    crate::charge::Info {
+      r#type: crate::charge::Types::LIST(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `query` - The search query string, see [Search Query Language](https://stripe.com/docs/search#search-query-language)
+  /// * `body` - The content that provides details for Stripe, e.g. B. `page` from `next_page`
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.search_charge("amount>999 AND metadata['order_id']:'6735'", None).get_search().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn search_charge(&self, query: &str, body: Option<String>) -> crate::charge::Info<'_> {
+    crate::charge::Info {
+      r#type: crate::charge::Types::SEARCH(format!("query={};{}", crate::helper::encode(query), body.unwrap_or_default())),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,133 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[macro_use] extern crate ezstripe;
+  /// 
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   // Returns: String("mode=payment;line_items[0][price]=PRICE_ID;line_items[0][quantity]=1;success_url=https://example.com/success;")
+  ///   let stripe_body = ezbody!(
+  ///       "mode" => "payment",
+  ///       "line_items[0][price]" => "PRICE_ID",
+  ///       "line_items[0][quantity]" => 1,
+  ///       "success_url" => "https://example.com/success"
+  ///     );
+  ///   
+  ///   let stripe_response = client.create_checkout_session(stripe_body).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_checkout_session(&self, body: String) -> crate::checkout::Info<'_> {
+    crate::checkout::Info {
+      r#type: crate::checkout::Types::CREATE(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_checkout_session("CHECKOUT_SESSION_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_checkout_session(&self, id: String) -> crate::checkout::Info<'_> {
+    crate::checkout::Info {
+      r#type: crate::checkout::Types::RETRIEVE(id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Expand
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.expire_checkout_session("CHECKOUT_SESSION_ID".to_string(), None).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn expire_checkout_session(&self, id: String, body: Option<String>) -> crate::checkout::Info<'_> {
+    crate::checkout::Info {
+      r#type: crate::checkout::Types::EXPIRE(id, body.unwrap_or_default()),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_checkout_session("limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_checkout_session(&self, body: String) -> crate::checkout::Info<'_> {
+    crate::checkout::Info {
+      r#type: crate::checkout::Types::LIST(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Limit
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_checkout_session_line_items("CHECKOUT_SESSION_ID".to_string(), "limit=100;".to_string()).get_line_items().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_checkout_session_line_items(&self, id: String, body: String) -> crate::checkout::Info<'_> {
+    crate::checkout::Info {
+      r#type: crate::checkout::Types::LIST_LINE_ITEMS(id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
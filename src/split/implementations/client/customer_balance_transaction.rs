@@ -0,0 +1,102 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `customer` - The unique ID of the customer
+  /// * `body` - The content that provides details for Stripe, e.g. B. Amount
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.create_customer_balance_transaction("CUSTOMER_ID".to_string(), "amount=-500;currency=eur;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_customer_balance_transaction(&self, customer: String, body: String) -> crate::customer_balance_transaction::Info<'_> {
+    crate::customer_balance_transaction::Info {
+      r#type: crate::customer_balance_transaction::Types::CREATE(customer, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `customer` - The unique ID of the customer
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_customer_balance_transaction("CUSTOMER_ID".to_string(), "TRANSACTION_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_customer_balance_transaction(&self, customer: String, id: String) -> crate::customer_balance_transaction::Info<'_> {
+    crate::customer_balance_transaction::Info {
+      r#type: crate::customer_balance_transaction::Types::RETRIEVE(customer, id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `customer` - The unique ID of the customer
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Description
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_customer_balance_transaction("CUSTOMER_ID".to_string(), "TRANSACTION_ID".to_string(), "metadata[order_id]=6735;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_customer_balance_transaction(&self, customer: String, id: String, body: String) -> crate::customer_balance_transaction::Info<'_> {
+    crate::customer_balance_transaction::Info {
+      r#type: crate::customer_balance_transaction::Types::UPDATE(customer, id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `customer` - The unique ID of the customer
+  /// * `body` - The content that provides details for Stripe, e.g. B. Limit
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_customer_balance_transaction("CUSTOMER_ID".to_string(), "limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_customer_balance_transaction(&self, customer: String, body: String) -> crate::customer_balance_transaction::Info<'_> {
+    crate::customer_balance_transaction::Info {
+      r#type: crate::customer_balance_transaction::Types::LIST(customer, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
@@ -14,11 +14,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn retrieve_dispute(&self, id: String) -> crate::dispute::Info {
+  pub fn retrieve_dispute(&self, id: String) -> crate::dispute::Info<'_> {
     crate::dispute::Info {
       r#type: crate::dispute::Types::RETRIEVE(id),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -38,11 +39,44 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn update_dispute(&self, id: String, body: String) -> crate::dispute::Info {
+  pub fn update_dispute(&self, id: String, body: String) -> crate::dispute::Info<'_> {
     crate::dispute::Info {
       r#type: crate::dispute::Types::UPDATE(id, body),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// Sending fails without a request to Stripe if the text fields of the evidence exceed `dispute::EVIDENCE_TEXT_LIMIT` characters.
+  /// 
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID of the dispute
+  /// * `params` - The evidence and whether it should be submitted
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let mut stripe_params = ezstripe::dispute::EvidenceParams::default();
+  ///   stripe_params.evidence.customer_name = Some("Jenny Rosen".to_string());
+  ///   stripe_params.evidence.product_description = Some("Premium subscription".to_string());
+  ///   stripe_params.submit = true;
+  ///   
+  ///   let stripe_response = client.update_dispute_evidence("DISPUTE_ID".to_string(), stripe_params).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_dispute_evidence(&self, id: String, params: crate::dispute::EvidenceParams) -> crate::dispute::Info<'_> {
+    crate::dispute::Info {
+      r#type: crate::dispute::Types::UPDATE_EVIDENCE(id, Box::new(params)),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -61,11 +95,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn close_dispute(&self, id: String) -> crate::dispute::Info {
+  pub fn close_dispute(&self, id: String) -> crate::dispute::Info<'_> {
     crate::dispute::Info {
       r#type: crate::dispute::Types::CLOSE(id),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -84,11 +119,85 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn list_dispute(&self, body: String) -> crate::dispute::Info {
+  pub fn list_dispute(&self, body: String) -> crate::dispute::Info<'_> {
     crate::dispute::Info {
       r#type: crate::dispute::Types::LIST(body),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
+
+  /// Sends "GET" requests to Stripe's API until all disputes have been received and returns those that still need a response (`dispute::Status::needs_response()`).
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(disputes) = client.list_disputes_needing_response().await {
+  ///     for r in disputes {
+  ///       println!("{}: {:?} seconds left, missing {:?}", r.id, r.seconds_until_due(), r.missing_evidence());
+  ///     }
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub async fn list_disputes_needing_response(&self) -> Result<Vec<crate::dispute::Response>, (String, Option<crate::error::Info>)> {
+    let mut result: Vec<crate::dispute::Response> = Vec::new();
+    let mut starting_after = String::new();
+    loop {
+      let page = self.list_dispute(format!("limit=100;{}", starting_after)).get_list().await?;
+      let has_more = page.has_more && !page.data.is_empty();
+      if let Some(r) = page.data.last() {
+        starting_after = format!("starting_after={};", r.id);
+      }
+      result.extend(page.data.into_iter().filter(|r| r.status.needs_response()));
+      if !has_more {
+        break;
+      }
+    }
+
+    Ok(result)
+  }
+
+  /// Uploads a file as evidence, stores its ID in the `field` of `params` and updates the dispute with all evidence of `params`.
+  /// 
+  /// `params` is validated before the upload, so no file is uploaded if Stripe would reject the evidence.
+  /// 
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID of the dispute
+  /// * `field` - The field of the evidence the uploaded file belongs to
+  /// * `file_name` - The name of the file, e.g. `receipt.pdf`
+  /// * `content` - The content of the file
+  /// * `params` - The remaining evidence and whether it should be submitted
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let mut params = ezstripe::dispute::EvidenceParams::default();
+  ///   params.evidence.product_description = Some("Premium subscription".to_string());
+  ///   params.submit = true;
+  ///   
+  ///   let content = ezstripe::file::Content::from(b"RECEIPT".to_vec());
+  ///   let stripe_response = client.upload_dispute_evidence("DISPUTE_ID".to_string(), ezstripe::dispute::EvidenceFile::Receipt, "receipt.pdf".to_string(), content, params).await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  #[cfg(feature = "file")]
+  pub async fn upload_dispute_evidence(&self, id: String, field: crate::dispute::EvidenceFile, file_name: String, content: crate::file::Content, mut params: crate::dispute::EvidenceParams) -> Result<crate::dispute::Response, (String, Option<crate::error::Info>)> {
+    params.validate().map_err(|r| (r, None))?;
+
+    let file = self.create_file(crate::file::Purpose::DisputeEvidence, file_name, content, None).send().await?;
+    params.set_file(field, file.id);
+
+    self.update_dispute_evidence(id, params).send().await
+  }
 }
\ No newline at end of file
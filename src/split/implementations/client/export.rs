@@ -0,0 +1,49 @@
+impl Client {
+  /// Sends "GET" requests to Stripe's API until all balance transactions created in the given range have been received and writes them to `writer`.
+  /// 
+  /// Every page is written before the next one is requested, so the history is never kept in memory.
+  /// Returns the number of written balance transactions.
+  /// 
+  /// # Arguments
+  /// 
+  /// * `created_gte` - Only export balance transactions created at or after this time (seconds since the Unix epoch)
+  /// * `created_lt` - Only export balance transactions created before this time (seconds since the Unix epoch)
+  /// * `format` - The format of the export, one `crate::export::BalanceTransactionRow` per row or line
+  /// * `writer` - The destination of the export, e.g. a `std::fs::File`
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let mut output: Vec<u8> = Vec::new();
+  ///   let stripe_response = client.export_balance_transactions(1672531200, 1675209600, ezstripe::export::Format::Csv, &mut output).await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub async fn export_balance_transactions<W: std::io::Write>(&self, created_gte: i64, created_lt: i64, format: crate::export::Format, writer: W) -> Result<usize, (String, Option<crate::error::Info>)> {
+    let mut writer = crate::export::Writer::create(format, writer);
+    let mut count: usize = 0;
+    let mut starting_after = String::new();
+    loop {
+      let page = self.list_balance_transaction(format!("created[gte]={};created[lt]={};limit=100;{}", created_gte, created_lt, starting_after)).get_list().await?;
+      let has_more = page.has_more && !page.data.is_empty();
+      if let Some(r) = page.data.last() {
+        starting_after = format!("starting_after={};", r.id);
+      }
+      for r in page.data.iter() {
+        writer.write(&crate::export::BalanceTransactionRow::from(r)).map_err(|r| (r, None))?;
+        count += 1;
+      }
+      if !has_more {
+        break;
+      }
+    }
+
+    writer.flush().map_err(|r| (r, None))?;
+
+    Ok(count)
+  }
+}
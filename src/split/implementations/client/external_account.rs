@@ -0,0 +1,154 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the account, use the ID of your own account for the platform
+  /// * `body` - The content that provides details for Stripe, e.g. B. External account token
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.create_external_account("ACCOUNT_ID".to_string(), "external_account=btok_1234;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_external_account(&self, account: String, body: String) -> crate::external_account::Info<'_> {
+    crate::external_account::Info {
+      r#type: crate::external_account::Types::CREATE(account, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the account, use the ID of your own account for the platform
+  /// * `id` - The unique ID of the bank account or card
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_external_account("ACCOUNT_ID".to_string(), "BANK_ACCOUNT_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_external_account(&self, account: String, id: String) -> crate::external_account::Info<'_> {
+    crate::external_account::Info {
+      r#type: crate::external_account::Types::RETRIEVE(account, id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the account, use the ID of your own account for the platform
+  /// * `id` - The unique ID of the bank account or card
+  /// * `body` - The content that provides details for Stripe, e.g. B. Metadata
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_external_account("ACCOUNT_ID".to_string(), "BANK_ACCOUNT_ID".to_string(), "metadata[order_id]=6735;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_external_account(&self, account: String, id: String, body: String) -> crate::external_account::Info<'_> {
+    crate::external_account::Info {
+      r#type: crate::external_account::Types::UPDATE(account, id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the account, use the ID of your own account for the platform
+  /// * `id` - The unique ID of the bank account or card
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.delete_external_account("ACCOUNT_ID".to_string(), "BANK_ACCOUNT_ID".to_string()).delete().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn delete_external_account(&self, account: String, id: String) -> crate::external_account::Info<'_> {
+    crate::external_account::Info {
+      r#type: crate::external_account::Types::DELETE(account, id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the account, use the ID of your own account for the platform
+  /// * `body` - The content that provides details for Stripe, e.g. B. `object=bank_account;`
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_external_account("ACCOUNT_ID".to_string(), String::new()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_external_account(&self, account: String, body: String) -> crate::external_account::Info<'_> {
+    crate::external_account::Info {
+      r#type: crate::external_account::Types::LIST(account, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// Makes the bank account or card the default external account for its currency.
+  /// 
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the account, use the ID of your own account for the platform
+  /// * `id` - The unique ID of the bank account or card
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.set_default_external_account("ACCOUNT_ID".to_string(), "BANK_ACCOUNT_ID".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn set_default_external_account(&self, account: String, id: String) -> crate::external_account::Info<'_> {
+    crate::external_account::Info {
+      r#type: crate::external_account::Types::UPDATE(account, id, "default_for_currency=true;".to_string()),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,106 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `purpose` - The purpose of the uploaded file
+  /// * `file_name` - The name of the file, e.g. `receipt.pdf`
+  /// * `content` - The content of the file, either in memory or streamed with `crate::file::Content::from_reader()`
+  /// * `body` - The content that provides details for Stripe, e.g. B. File link data
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(file) = tokio::fs::File::open("receipt.pdf").await {
+  ///     let content = ezstripe::file::Content::from_reader(file);
+  ///     let stripe_response = client.create_file(ezstripe::file::Purpose::DisputeEvidence, "receipt.pdf".to_string(), content, None).send().await;
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_file(&self, purpose: crate::file::Purpose, file_name: String, content: crate::file::Content, body: Option<String>) -> crate::file::Upload<'_> {
+    crate::file::Upload {
+      purpose,
+      file_name,
+      content,
+      body,
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_file("FILE_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_file(&self, id: String) -> crate::file::Info<'_> {
+    crate::file::Info {
+      r#type: crate::file::Types::RETRIEVE(id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Purpose
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_file("purpose=dispute_evidence;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_file(&self, body: String) -> crate::file::Info<'_> {
+    crate::file::Info {
+      r#type: crate::file::Types::LIST(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_file_contents("FILE_ID".to_string()).download().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_file_contents(&self, id: String) -> crate::file::Info<'_> {
+    crate::file::Info {
+      r#type: crate::file::Types::CONTENTS(id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,354 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[macro_use] extern crate ezstripe;
+  /// 
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   // Returns: String("customer=CUSTOMER_ID;collection_method=send_invoice;days_until_due=30;")
+  ///   let stripe_body = ezbody!(
+  ///       "customer" => "CUSTOMER_ID",
+  ///       "collection_method" => "send_invoice",
+  ///       "days_until_due" => 30
+  ///     );
+  ///   
+  ///   let stripe_response = client.create_invoice(stripe_body).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_invoice(&self, body: String) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::CREATE(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_invoice("INVOICE_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_invoice(&self, id: String) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::RETRIEVE(id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Description
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_invoice("INVOICE_ID".to_string(), "metadata[order_id]=6735;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_invoice(&self, id: String, body: String) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::UPDATE(id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.delete_invoice("INVOICE_ID".to_string()).delete().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn delete_invoice(&self, id: String) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::DELETE(id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Auto advance
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.finalize_invoice("INVOICE_ID".to_string(), None).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn finalize_invoice(&self, id: String, body: Option<String>) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::FINALIZE(id, body.unwrap_or_default()),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Payment method
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.pay_invoice("INVOICE_ID".to_string(), None).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn pay_invoice(&self, id: String, body: Option<String>) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::PAY(id, body.unwrap_or_default()),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Expand
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.send_invoice("INVOICE_ID".to_string(), None).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn send_invoice(&self, id: String, body: Option<String>) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::SEND(id, body.unwrap_or_default()),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Expand
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.void_invoice("INVOICE_ID".to_string(), None).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn void_invoice(&self, id: String, body: Option<String>) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::VOID(id, body.unwrap_or_default()),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Expand
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.mark_uncollectible_invoice("INVOICE_ID".to_string(), None).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn mark_uncollectible_invoice(&self, id: String, body: Option<String>) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::MARK_UNCOLLECTIBLE(id, body.unwrap_or_default()),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_invoice("limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_invoice(&self, body: String) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::LIST(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `query` - The search query string, see [Search Query Language](https://stripe.com/docs/search#search-query-language)
+  /// * `body` - The content that provides details for Stripe, e.g. B. `page` from `next_page`
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.search_invoice("total>999 AND metadata['order_id']:'6735'", None).get_search().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn search_invoice(&self, query: &str, body: Option<String>) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::SEARCH(format!("query={};{}", crate::helper::encode(query), body.unwrap_or_default())),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Customer or subscription changes to simulate prorations
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.upcoming_invoice("customer=CUSTOMER_ID;subscription=SUBSCRIPTION_ID;subscription_items[0][id]=SUBSCRIPTION_ITEM_ID;subscription_items[0][price]=PRICE_ID;subscription_proration_date=1672531200;".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn upcoming_invoice(&self, body: String) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::UPCOMING(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Limit
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_invoice_line_items("INVOICE_ID".to_string(), "limit=100;".to_string()).get_all_line_items().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_invoice_line_items(&self, id: String, body: String) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::LIST_LINE_ITEMS(id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Customer or subscription
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_upcoming_invoice_line_items("customer=CUSTOMER_ID;".to_string()).get_line_items().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_upcoming_invoice_line_items(&self, body: String) -> crate::invoice::Info<'_> {
+    crate::invoice::Info {
+      r#type: crate::invoice::Types::UPCOMING_LINE_ITEMS(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
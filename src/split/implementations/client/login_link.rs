@@ -0,0 +1,26 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the connected account
+  /// * `body` - The content that provides details for Stripe, e.g. B. Expand
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.create_login_link("ACCOUNT_ID".to_string(), None).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_login_link(&self, account: String, body: Option<String>) -> crate::login_link::Info<'_> {
+    crate::login_link::Info {
+      r#type: crate::login_link::Types::CREATE(account, body.unwrap_or_default()),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
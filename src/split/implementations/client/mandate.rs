@@ -14,11 +14,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn retrieve_mandate(&self, id: String) -> crate::mandate::Info {
+  pub fn retrieve_mandate(&self, id: String) -> crate::mandate::Info<'_> {
     crate::mandate::Info {
       id,
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 }
\ No newline at end of file
@@ -24,11 +24,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn create_payment_intent(&self, body: String) -> crate::payment_intent::Info {
+  pub fn create_payment_intent(&self, body: String) -> crate::payment_intent::Info<'_> {
     crate::payment_intent::Info {
       r#type: crate::payment_intent::Types::CREATE(body),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -47,11 +48,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn retrieve_payment_intent(&self, id: String) -> crate::payment_intent::Info {
+  pub fn retrieve_payment_intent(&self, id: String) -> crate::payment_intent::Info<'_> {
     crate::payment_intent::Info {
       r#type: crate::payment_intent::Types::RETRIEVE(id),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -71,11 +73,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn confirm_payment_intent(&self, id: String, body: Option<String>) -> crate::payment_intent::Info {
+  pub fn confirm_payment_intent(&self, id: String, body: Option<String>) -> crate::payment_intent::Info<'_> {
     crate::payment_intent::Info {
-      r#type: crate::payment_intent::Types::CONFIRM(id, body.unwrap_or(String::default())),
+      r#type: crate::payment_intent::Types::CONFIRM(id, body.unwrap_or_default()),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -95,11 +98,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn cancel_payment_intent(&self, id: String, body: Option<String>) -> crate::payment_intent::Info {
+  pub fn cancel_payment_intent(&self, id: String, body: Option<String>) -> crate::payment_intent::Info<'_> {
     crate::payment_intent::Info {
-      r#type: crate::payment_intent::Types::CANCEL(id, body.unwrap_or(String::default())),
+      r#type: crate::payment_intent::Types::CANCEL(id, body.unwrap_or_default()),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -119,17 +123,19 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn update_payment_intent(&self, id: String, body: String) -> crate::payment_intent::Info {
+  pub fn update_payment_intent(&self, id: String, body: String) -> crate::payment_intent::Info<'_> {
     crate::payment_intent::Info {
       r#type: crate::payment_intent::Types::UPDATE(id, body),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
   /// # Arguments
   /// 
   /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Amount to capture
   /// 
   /// # Example
   /// ```
@@ -137,16 +143,17 @@ impl Client {
   /// async fn main() {
   ///   let client = ezstripe::Client::new("SECRET_KEY");
   ///   
-  ///   let stripe_response = client.capture_payment_intent("PAYMENT_INTENT_ID".to_string()).send().await;
+  ///   let stripe_response = client.capture_payment_intent("PAYMENT_INTENT_ID".to_string(), Some("amount_to_capture=1500;".to_string())).send().await;
   ///   
   ///   // ...
   /// }
   /// ```
-  pub fn capture_payment_intent(&self, id: String) -> crate::payment_intent::Info {
+  pub fn capture_payment_intent(&self, id: String, body: Option<String>) -> crate::payment_intent::Info<'_> {
     crate::payment_intent::Info {
-      r#type: crate::payment_intent::Types::CAPTURE(id),
+      r#type: crate::payment_intent::Types::CAPTURE(id, body.unwrap_or_default()),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -165,11 +172,112 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn list_payment_intent(&self, body: String) -> crate::payment_intent::Info {
+  pub fn list_payment_intent(&self, body: String) -> crate::payment_intent::Info<'_> {
     crate::payment_intent::Info {
       r#type: crate::payment_intent::Types::LIST(body),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `query` - The search query string, see [Search Query Language](https://stripe.com/docs/search#search-query-language)
+  /// * `body` - The content that provides details for Stripe, e.g. B. `page` from `next_page`
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.search_payment_intent("status:'succeeded' AND metadata['order_id']:'6735'", None).get_search().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn search_payment_intent(&self, query: &str, body: Option<String>) -> crate::payment_intent::Info<'_> {
+    crate::payment_intent::Info {
+      r#type: crate::payment_intent::Types::SEARCH(format!("query={};{}", crate::helper::encode(query), body.unwrap_or_default())),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. The updated total amount
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.increment_authorization_payment_intent("PAYMENT_INTENT_ID".to_string(), "amount=2099;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn increment_authorization_payment_intent(&self, id: String, body: String) -> crate::payment_intent::Info<'_> {
+    crate::payment_intent::Info {
+      r#type: crate::payment_intent::Types::INCREMENT_AUTHORIZATION(id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Amount
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.apply_customer_balance_payment_intent("PAYMENT_INTENT_ID".to_string(), None).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn apply_customer_balance_payment_intent(&self, id: String, body: Option<String>) -> crate::payment_intent::Info<'_> {
+    crate::payment_intent::Info {
+      r#type: crate::payment_intent::Types::APPLY_CUSTOMER_BALANCE(id, body.unwrap_or_default()),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Amounts or descriptor code
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.verify_microdeposits_payment_intent("PAYMENT_INTENT_ID".to_string(), "amounts[]=32;amounts[]=45;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn verify_microdeposits_payment_intent(&self, id: String, body: String) -> crate::payment_intent::Info<'_> {
+    crate::payment_intent::Info {
+      r#type: crate::payment_intent::Types::VERIFY_MICRODEPOSITS(id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 }
\ No newline at end of file
@@ -0,0 +1,131 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Line items
+  /// 
+  /// # Example
+  /// ```
+  /// use ezstripe::payment_link::{ AfterCompletionParams, LineItemParams, LineItemsParams };
+  /// 
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   // Returns: String("line_items[0][price]=PRICE_ID;line_items[0][quantity]=1;after_completion[type]=redirect;after_completion[redirect][url]=https%3A%2F%2Fexample.com;")
+  ///   let stripe_body = format!("{}{}",
+  ///       LineItemsParams(vec![LineItemParams { price: "PRICE_ID".to_string(), quantity: 1, adjustable_quantity: None }]),
+  ///       AfterCompletionParams::Redirect("https://example.com".to_string())
+  ///     );
+  ///   
+  ///   let stripe_response = client.create_payment_link(stripe_body).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_payment_link(&self, body: String) -> crate::payment_link::Info<'_> {
+    crate::payment_link::Info {
+      r#type: crate::payment_link::Types::CREATE(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_payment_link("PAYMENT_LINK_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_payment_link(&self, id: String) -> crate::payment_link::Info<'_> {
+    crate::payment_link::Info {
+      r#type: crate::payment_link::Types::RETRIEVE(id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Active
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_payment_link("PAYMENT_LINK_ID".to_string(), "active=false;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_payment_link(&self, id: String, body: String) -> crate::payment_link::Info<'_> {
+    crate::payment_link::Info {
+      r#type: crate::payment_link::Types::UPDATE(id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_payment_link("limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_payment_link(&self, body: String) -> crate::payment_link::Info<'_> {
+    crate::payment_link::Info {
+      r#type: crate::payment_link::Types::LIST(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Limit
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_payment_link_line_items("PAYMENT_LINK_ID".to_string(), "limit=100;".to_string()).get_line_items().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_payment_link_line_items(&self, id: String, body: String) -> crate::payment_link::Info<'_> {
+    crate::payment_link::Info {
+      r#type: crate::payment_link::Types::LIST_LINE_ITEMS(id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
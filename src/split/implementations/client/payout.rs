@@ -1,32 +1,33 @@
 impl Client {
+  /// Sending fails without a request to Stripe if `amount` is 0 or `currency` is empty.
+  /// 
   /// # Arguments
   /// 
-  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// * `params` - The amount and currency of the payout and further details, e.g. B. Destination
   /// 
   /// # Example
   /// ```
-  /// #[macro_use] extern crate ezstripe;
-  /// 
   /// #[tokio::main]
   /// async fn main() {
   ///   let client = ezstripe::Client::new("SECRET_KEY");
   ///   
-  ///   // Returns: String("amount=1500;currency=eur;payment_method_types[]=card;capture_method=manual;")
-  ///   let stripe_body = ezbody!(
-  ///       "amount" => 1500,
-  ///       "currency" => "eur"
-  ///     );
+  ///   let stripe_params = ezstripe::payout::PayoutParams {
+  ///       amount: 1500,
+  ///       currency: "eur".to_string(),
+  ///       ..Default::default()
+  ///     };
   ///   
-  ///   let stripe_response = client.create_payout(stripe_body).send().await;
+  ///   let stripe_response = client.create_payout(stripe_params).send().await;
   ///   
   ///   // ...
   /// }
   /// ```
-  pub fn create_payout(&self, body: String) -> crate::payout::Info {
+  pub fn create_payout(&self, params: crate::payout::PayoutParams) -> crate::payout::Info<'_> {
     crate::payout::Info {
-      r#type: crate::payout::Types::CREATE(body),
+      r#type: crate::payout::Types::CREATE(params),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -45,11 +46,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn retrieve_payout(&self, id: String) -> crate::payout::Info {
+  pub fn retrieve_payout(&self, id: String) -> crate::payout::Info<'_> {
     crate::payout::Info {
       r#type: crate::payout::Types::RETRIEVE(id),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -69,11 +71,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn update_payout(&self, id: String, body: String) -> crate::payout::Info {
+  pub fn update_payout(&self, id: String, body: String) -> crate::payout::Info<'_> {
     crate::payout::Info {
       r#type: crate::payout::Types::UPDATE(id, body),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -92,11 +95,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn list_payout(&self, body: String) -> crate::payout::Info {
+  pub fn list_payout(&self, body: String) -> crate::payout::Info<'_> {
     crate::payout::Info {
       r#type: crate::payout::Types::LIST(body),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -115,11 +119,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn cancel_payout(&self, id: String) -> crate::payout::Info {
+  pub fn cancel_payout(&self, id: String) -> crate::payout::Info<'_> {
     crate::payout::Info {
       r#type: crate::payout::Types::CANCEL(id),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -139,11 +144,56 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn reverse_payout(&self, id: String, body: Option<String>) -> crate::payout::Info {
+  pub fn reverse_payout(&self, id: String, body: Option<String>) -> crate::payout::Info<'_> {
     crate::payout::Info {
-      r#type: crate::payout::Types::REVERSE(id, body.unwrap_or(String::default())),
+      r#type: crate::payout::Types::REVERSE(id, body.unwrap_or_default()),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// Checks `crate::balance::Response::instant_available` and creates an instant payout if enough funds are available.
+  /// 
+  /// `params.method` is always set to `instant`.
+  /// 
+  /// # Arguments
+  /// 
+  /// * `params` - The parameters of the payout
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let params = ezstripe::payout::PayoutParams {
+  ///       amount: 1500,
+  ///       currency: "eur".to_string(),
+  ///       destination: Some("CARD_ID".to_string()),
+  ///       ..Default::default()
+  ///     };
+  ///   
+  ///   let stripe_response = client.create_instant_payout(params).await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  #[cfg(feature = "balance")]
+  pub async fn create_instant_payout(&self, mut params: crate::payout::PayoutParams) -> Result<crate::payout::Response, (String, Option<crate::error::Info>)> {
+    params.validate().map_err(|r| (r, None))?;
+
+    let balance = self.retrieve_balance().get().await?;
+    let source_type = params.source_type.map(|r| r.to_string());
+    let available = balance.instant_available_amount(&params.currency, source_type.as_deref());
+    if available < params.amount as i64 {
+      if log::log_enabled!(log::Level::Error) {
+        log::error!("Only {} of {} {} can be paid out instantly", available, params.amount, params.currency);
+      }
+      return Err(("Not enough funds are available for an instant payout".to_string(), None));
     }
+
+    params.method = Some(crate::payout::Method::Instant);
+    self.create_payout(params).send().await
   }
 }
\ No newline at end of file
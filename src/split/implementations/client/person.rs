@@ -0,0 +1,127 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the connected account
+  /// * `body` - The content that provides details for Stripe, e.g. B. Name
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.create_person("ACCOUNT_ID".to_string(), "first_name=Jane;last_name=Diaz;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_person(&self, account: String, body: String) -> crate::person::Info<'_> {
+    crate::person::Info {
+      r#type: crate::person::Types::CREATE(account, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the connected account
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_person("ACCOUNT_ID".to_string(), "PERSON_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_person(&self, account: String, id: String) -> crate::person::Info<'_> {
+    crate::person::Info {
+      r#type: crate::person::Types::RETRIEVE(account, id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the connected account
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Relationship
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_person("ACCOUNT_ID".to_string(), "PERSON_ID".to_string(), "metadata[order_id]=6735;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_person(&self, account: String, id: String, body: String) -> crate::person::Info<'_> {
+    crate::person::Info {
+      r#type: crate::person::Types::UPDATE(account, id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the connected account
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.delete_person("ACCOUNT_ID".to_string(), "PERSON_ID".to_string()).delete().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn delete_person(&self, account: String, id: String) -> crate::person::Info<'_> {
+    crate::person::Info {
+      r#type: crate::person::Types::DELETE(account, id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the connected account
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_person("ACCOUNT_ID".to_string(), "limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_person(&self, account: String, body: String) -> crate::person::Info<'_> {
+    crate::person::Info {
+      r#type: crate::person::Types::LIST(account, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,158 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[macro_use] extern crate ezstripe;
+  /// 
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   // Returns: String("unit_amount=2000;currency=eur;recurring[interval]=month;product=PRODUCT_ID;")
+  ///   let stripe_body = ezbody!(
+  ///       "unit_amount" => 2000,
+  ///       "currency" => "eur",
+  ///       "recurring[interval]" => "month",
+  ///       "product" => "PRODUCT_ID"
+  ///     );
+  ///   
+  ///   let stripe_response = client.create_price(stripe_body).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_price(&self, body: String) -> crate::price::Info<'_> {
+    crate::price::Info {
+      r#type: crate::price::Types::CREATE(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_price("PRICE_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_price(&self, id: String) -> crate::price::Info<'_> {
+    crate::price::Info {
+      r#type: crate::price::Types::RETRIEVE(id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Lookup key
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_price("PRICE_ID".to_string(), "lookup_key=gold_monthly;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_price(&self, id: String, body: String) -> crate::price::Info<'_> {
+    crate::price::Info {
+      r#type: crate::price::Types::UPDATE(id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_price("limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_price(&self, body: String) -> crate::price::Info<'_> {
+    crate::price::Info {
+      r#type: crate::price::Types::LIST(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `lookup_keys` - Only return prices with these lookup keys (up to 10)
+  /// * `body` - The content that provides details for Stripe, e.g. B. `active`
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_price_by_lookup_keys(&["gold_monthly", "gold_yearly"], None).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_price_by_lookup_keys(&self, lookup_keys: &[&str], body: Option<String>) -> crate::price::Info<'_> {
+    crate::price::Info {
+      r#type: crate::price::Types::LIST(format!("{}{}", lookup_keys.iter().map(|r| format!("lookup_keys[]={};", crate::helper::encode(r))).collect::<String>(), body.unwrap_or_default())),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `query` - The search query string, see [Search Query Language](https://stripe.com/docs/search#search-query-language)
+  /// * `body` - The content that provides details for Stripe, e.g. B. `page` from `next_page`
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.search_price("active:'true' AND metadata['order_id']:'6735'", None).get_search().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn search_price(&self, query: &str, body: Option<String>) -> crate::price::Info<'_> {
+    crate::price::Info {
+      r#type: crate::price::Types::SEARCH(format!("query={};{}", crate::helper::encode(query), body.unwrap_or_default())),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
@@ -1,32 +1,37 @@
 impl Client {
+  /// Sending fails without a request to Stripe if neither `charge` nor `payment_intent` is set.
+  /// 
   /// # Arguments
   /// 
-  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// * `params` - The charge or PaymentIntent to refund and further details, e.g. B. Amount
   /// 
   /// # Example
   /// ```
-  /// #[macro_use] extern crate ezstripe;
-  /// 
   /// #[tokio::main]
   /// async fn main() {
   ///   let client = ezstripe::Client::new("SECRET_KEY");
   ///   
-  ///   // Returns: String("amount=1500;currency=eur;payment_method_types[]=card;capture_method=manual;")
-  ///   let stripe_body = ezbody!(
-  ///       "amount" => 1500,
-  ///       "currency" => "eur"
-  ///     );
+  ///   let stripe_params = ezstripe::refund::RefundParams {
+  ///       payment_intent: Some("PAYMENT_INTENT_ID".to_string()),
+  ///       amount: Some(500),
+  ///       reason: Some(ezstripe::refund::Reason::RequestedByCustomer),
+  ///       ..Default::default()
+  ///     };
   ///   
-  ///   let stripe_response = client.create_refund(stripe_body).send().await;
+  ///   let stripe_response = client.create_refund(stripe_params).send().await;
+  ///   
+  ///   // Fails before sending: Err(("Either `charge` or `payment_intent` is required", None))
+  ///   let stripe_response = client.create_refund(ezstripe::refund::RefundParams::default()).send().await;
   ///   
   ///   // ...
   /// }
   /// ```
-  pub fn create_refund(&self, body: String) -> crate::refund::Info {
+  pub fn create_refund(&self, params: crate::refund::RefundParams) -> crate::refund::Info<'_> {
     crate::refund::Info {
-      r#type: crate::refund::Types::CREATE(body),
+      r#type: crate::refund::Types::CREATE(params),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -45,11 +50,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn retrieve_refund(&self, id: String) -> crate::refund::Info {
+  pub fn retrieve_refund(&self, id: String) -> crate::refund::Info<'_> {
     crate::refund::Info {
       r#type: crate::refund::Types::RETRIEVE(id),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -69,11 +75,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn update_refund(&self, id: String, body: String) -> crate::refund::Info {
+  pub fn update_refund(&self, id: String, body: String) -> crate::refund::Info<'_> {
     crate::refund::Info {
       r#type: crate::refund::Types::UPDATE(id, body),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -92,11 +99,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn list_refund(&self, body: String) -> crate::refund::Info {
+  pub fn list_refund(&self, body: String) -> crate::refund::Info<'_> {
     crate::refund::Info {
       r#type: crate::refund::Types::LIST(body),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 
@@ -115,35 +123,12 @@ impl Client {
   ///   // ...
   /// }
   /// ```
-  pub fn cancel_refund(&self, id: String) -> crate::refund::Info {
+  pub fn cancel_refund(&self, id: String) -> crate::refund::Info<'_> {
     crate::refund::Info {
       r#type: crate::refund::Types::CANCEL(id),
       secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
-    }
-  }
-
-  /// # Arguments
-  /// 
-  /// * `id` - The unique ID you received when you created it
-  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
-  /// 
-  /// # Example
-  /// ```
-  /// #[tokio::main]
-  /// async fn main() {
-  ///   let client = ezstripe::Client::new("SECRET_KEY");
-  ///   
-  ///   let stripe_response = client.reverse_refund("REFUND_ID".to_string(), None).get().await;
-  ///   
-  ///   // ...
-  /// }
-  /// ```
-  pub fn reverse_refund(&self, id: String, body: Option<String>) -> crate::refund::Info {
-    crate::refund::Info {
-      r#type: crate::refund::Types::REVERSE(id, body.unwrap_or(String::default())),
-      secret_key: self.secret_key.clone(),
-      reqwest_client: &self.reqwest_client
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
     }
   }
 }
\ No newline at end of file
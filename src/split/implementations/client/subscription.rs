@@ -0,0 +1,181 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[macro_use] extern crate ezstripe;
+  /// 
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   // Returns: String("customer=CUSTOMER_ID;items[0][price]=PRICE_ID;")
+  ///   let stripe_body = ezbody!(
+  ///       "customer" => "CUSTOMER_ID",
+  ///       "items[0][price]" => "PRICE_ID"
+  ///     );
+  ///   
+  ///   let stripe_response = client.create_subscription(stripe_body).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_subscription(&self, body: String) -> crate::subscription::Info<'_> {
+    crate::subscription::Info {
+      r#type: crate::subscription::Types::CREATE(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_subscription("SUBSCRIPTION_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_subscription(&self, id: String) -> crate::subscription::Info<'_> {
+    crate::subscription::Info {
+      r#type: crate::subscription::Types::RETRIEVE(id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Items or proration behavior
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_subscription("SUBSCRIPTION_ID".to_string(), format!("proration_behavior={};", ezstripe::subscription::ProrationBehavior::None)).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_subscription(&self, id: String, body: String) -> crate::subscription::Info<'_> {
+    crate::subscription::Info {
+      r#type: crate::subscription::Types::UPDATE(id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. `invoice_now` or `prorate`
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.cancel_subscription("SUBSCRIPTION_ID".to_string(), None).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn cancel_subscription(&self, id: String, body: Option<String>) -> crate::subscription::Info<'_> {
+    crate::subscription::Info {
+      r#type: crate::subscription::Types::CANCEL(id, body.unwrap_or_default()),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Billing cycle anchor or proration behavior
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.resume_subscription("SUBSCRIPTION_ID".to_string(), Some(format!("billing_cycle_anchor={};", ezstripe::subscription::BillingCycleAnchor::Now))).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn resume_subscription(&self, id: String, body: Option<String>) -> crate::subscription::Info<'_> {
+    crate::subscription::Info {
+      r#type: crate::subscription::Types::RESUME(id, body.unwrap_or_default()),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_subscription("limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_subscription(&self, body: String) -> crate::subscription::Info<'_> {
+    crate::subscription::Info {
+      r#type: crate::subscription::Types::LIST(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `query` - The search query string, see [Search Query Language](https://stripe.com/docs/search#search-query-language)
+  /// * `body` - The content that provides details for Stripe, e.g. B. `page` from `next_page`
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.search_subscription("status:'active' AND metadata['order_id']:'6735'", None).get_search().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn search_subscription(&self, query: &str, body: Option<String>) -> crate::subscription::Info<'_> {
+    crate::subscription::Info {
+      r#type: crate::subscription::Types::SEARCH(format!("query={};{}", crate::helper::encode(query), body.unwrap_or_default())),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,132 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[macro_use] extern crate ezstripe;
+  /// 
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   // Returns: String("subscription=SUBSCRIPTION_ID;price=PRICE_ID;quantity=2;")
+  ///   let stripe_body = ezbody!(
+  ///       "subscription" => "SUBSCRIPTION_ID",
+  ///       "price" => "PRICE_ID",
+  ///       "quantity" => 2
+  ///     );
+  ///   
+  ///   let stripe_response = client.create_subscription_item(stripe_body).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_subscription_item(&self, body: String) -> crate::subscription_item::Info<'_> {
+    crate::subscription_item::Info {
+      r#type: crate::subscription_item::Types::CREATE(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_subscription_item("SUBSCRIPTION_ITEM_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_subscription_item(&self, id: String) -> crate::subscription_item::Info<'_> {
+    crate::subscription_item::Info {
+      r#type: crate::subscription_item::Types::RETRIEVE(id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Quantity
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_subscription_item("SUBSCRIPTION_ITEM_ID".to_string(), "quantity=3;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_subscription_item(&self, id: String, body: String) -> crate::subscription_item::Info<'_> {
+    crate::subscription_item::Info {
+      r#type: crate::subscription_item::Types::UPDATE(id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Proration behavior
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.delete_subscription_item("SUBSCRIPTION_ITEM_ID".to_string(), None).delete().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn delete_subscription_item(&self, id: String, body: Option<String>) -> crate::subscription_item::Info<'_> {
+    crate::subscription_item::Info {
+      r#type: crate::subscription_item::Types::DELETE(id, body.unwrap_or_default()),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `body` - The content that provides details for Stripe, e.g. B. Subscription
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_subscription_item("subscription=SUBSCRIPTION_ID;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_subscription_item(&self, body: String) -> crate::subscription_item::Info<'_> {
+    crate::subscription_item::Info {
+      r#type: crate::subscription_item::Types::LIST(body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
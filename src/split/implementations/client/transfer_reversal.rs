@@ -0,0 +1,102 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `transfer` - The unique ID of the transfer
+  /// * `body` - The content that provides details for Stripe, e.g. B. Amount
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.create_transfer_reversal("TRANSFER_ID".to_string(), "amount=100;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_transfer_reversal(&self, transfer: String, body: String) -> crate::transfer_reversal::Info<'_> {
+    crate::transfer_reversal::Info {
+      r#type: crate::transfer_reversal::Types::CREATE(transfer, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `transfer` - The unique ID of the transfer
+  /// * `id` - The unique ID you received when you created it
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.retrieve_transfer_reversal("TRANSFER_ID".to_string(), "TRANSFER_REVERSAL_ID".to_string()).get().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_transfer_reversal(&self, transfer: String, id: String) -> crate::transfer_reversal::Info<'_> {
+    crate::transfer_reversal::Info {
+      r#type: crate::transfer_reversal::Types::RETRIEVE(transfer, id),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `transfer` - The unique ID of the transfer
+  /// * `id` - The unique ID you received when you created it
+  /// * `body` - The content that provides details for Stripe, e.g. B. Metadata
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.update_transfer_reversal("TRANSFER_ID".to_string(), "TRANSFER_REVERSAL_ID".to_string(), "metadata[order_id]=6735;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn update_transfer_reversal(&self, transfer: String, id: String, body: String) -> crate::transfer_reversal::Info<'_> {
+    crate::transfer_reversal::Info {
+      r#type: crate::transfer_reversal::Types::UPDATE(transfer, id, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `transfer` - The unique ID of the transfer
+  /// * `body` - The content that provides details for Stripe, e.g. B. Limit
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_transfer_reversal("TRANSFER_ID".to_string(), "limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_transfer_reversal(&self, transfer: String, body: String) -> crate::transfer_reversal::Info<'_> {
+    crate::transfer_reversal::Info {
+      r#type: crate::transfer_reversal::Types::LIST(transfer, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+impl Client {
+  /// # Arguments
+  /// 
+  /// * `subscription_item` - The unique ID of the subscription item
+  /// * `body` - The content that provides details for Stripe, e.g. B. Quantity
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.create_usage_record("SUBSCRIPTION_ITEM_ID".to_string(), "quantity=100;timestamp=1571252444;".to_string()).send().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn create_usage_record(&self, subscription_item: String, body: String) -> crate::usage_record::Info<'_> {
+    crate::usage_record::Info {
+      r#type: crate::usage_record::Types::CREATE(subscription_item, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+
+  /// # Arguments
+  /// 
+  /// * `subscription_item` - The unique ID of the subscription item
+  /// * `body` - The content that provides details for Stripe, e.g. B. Currency
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   let stripe_response = client.list_usage_record_summary("SUBSCRIPTION_ITEM_ID".to_string(), "limit=3;".to_string()).get_list().await;
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn list_usage_record_summary(&self, subscription_item: String, body: String) -> crate::usage_record::Info<'_> {
+    crate::usage_record::Info {
+      r#type: crate::usage_record::Types::LIST(subscription_item, body),
+      secret_key: self.secret_key.clone(),
+      reqwest_client: &self.reqwest_client,
+      lenient: self.lenient
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,107 @@
+/// Information about the requirements of an account, capability or person, including what information needs to be collected, and by when.
+/// 
+/// [Identity verification](https://stripe.com/docs/connect/identity-verification-api)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Requirements {
+  /// Fields that are due and can be satisfied by providing the corresponding alternative fields instead.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub alternatives: Option<Vec<RequirementAlternative>>,
+  /// Date by which the fields in `currently_due` must be collected to keep the account enabled.
+  /// These fields may disable the account sooner if the next threshold is reached before they are collected.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub current_deadline: Option<i64>,
+  /// Fields that need to be collected to keep the account enabled.
+  /// If not collected by `current_deadline`, these fields appear in `past_due` as well, and the account is disabled.
+  pub currently_due: Vec<String>,
+  /// If the account is disabled, this field describes why, see `DisabledReason`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub disabled_reason: Option<DisabledReason>,
+  /// Fields that are `currently_due` and need to be collected again because validation or verification failed.
+  pub errors: Vec<RequirementError>,
+  /// Fields that need to be collected assuming all volume thresholds are reached.
+  /// As they become required, they appear in `currently_due` as well, and `current_deadline` becomes set.
+  pub eventually_due: Vec<String>,
+  /// Fields that weren’t collected by `current_deadline`.
+  /// These fields need to be collected to enable the account.
+  pub past_due: Vec<String>,
+  /// Fields that may become required depending on the results of verification or review.
+  /// Will be an empty array unless an asynchronous verification is pending.
+  pub pending_verification: Vec<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+impl Requirements {
+  /// Returns `true` if fields need to be collected right now (`currently_due` or `past_due`).
+  pub fn is_action_required(&self) -> bool {
+    !self.currently_due.is_empty() || !self.past_due.is_empty()
+  }
+}
+
+/// Fields that can be provided instead of the `original_fields_due`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RequirementAlternative {
+  /// Fields that can be provided to satisfy all fields in `original_fields_due`.
+  pub alternative_fields_due: Vec<String>,
+  /// Fields that are due and can be satisfied by providing all fields in `alternative_fields_due`.
+  pub original_fields_due: Vec<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// A field that needs to be collected again because validation or verification failed.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RequirementError {
+  /// The code for the type of error, e.g. `verification_document_expired`.
+  pub code: String,
+  /// An informative message that indicates the error type and provides additional details about the error.
+  pub reason: String,
+  /// The specific user onboarding requirement field (in the requirements hash) that needs to be resolved.
+  pub requirement: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The reason why an account or capability is disabled.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DisabledReason {
+  /// Stripe needs more information to enable the account.
+  #[serde(rename = "requirements.past_due")]
+  RequirementsPastDue,
+  /// Stripe is verifying provided information.
+  #[serde(rename = "requirements.pending_verification")]
+  RequirementsPendingVerification,
+  /// The account might be on a prohibited persons or companies list.
+  #[serde(rename = "listed")]
+  Listed,
+  /// The platform has paused the account.
+  #[serde(rename = "platform_paused")]
+  PlatformPaused,
+  /// The account is rejected due to suspected fraud or illegal activity.
+  #[serde(rename = "rejected.fraud")]
+  RejectedFraud,
+  /// The account is rejected because it is on a third-party prohibited persons or companies list.
+  #[serde(rename = "rejected.listed")]
+  RejectedListed,
+  /// The account is rejected due to suspected terms of service violations.
+  #[serde(rename = "rejected.terms_of_service")]
+  RejectedTermsOfService,
+  /// The account is rejected for another reason.
+  #[serde(rename = "rejected.other")]
+  RejectedOther,
+  /// The account is under review by Stripe.
+  #[serde(rename = "under_review")]
+  UnderReview,
+  /// The account is disabled for another reason.
+  #[serde(rename = "other")]
+  Other,
+  /// A reason that is not (yet) part of this enumeration.
+  #[serde(other)]
+  Unknown
+}
+
+impl std::fmt::Display for DisabledReason {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+  }
+}
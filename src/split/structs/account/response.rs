@@ -0,0 +1,83 @@
+include!("requirements.rs");
+
+/// Account object from 10/19/2026
+/// 
+/// [Account object](https://stripe.com/docs/api/accounts/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // account
+  /// Business information about the account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub business_profile: Option<BusinessProfile>,
+  /// The business type, one of `individual`, `company`, `non_profit` or `government_entity`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub business_type: Option<String>,
+  /// A hash containing the set of capabilities that was requested for this account and their associated states.
+  /// Keys are names of capabilities, values are `active`, `inactive` or `pending`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub capabilities: Option<HashMap<String, String>>,
+  /// Whether the account can create live charges.
+  pub charges_enabled: bool,
+  /// The account’s country.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub country: Option<String>,
+  /// Time at which the account was connected.
+  /// Measured in seconds since the Unix epoch.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub created: Option<i64>,
+  /// Three-letter ISO currency code representing the default currency for the account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub default_currency: Option<String>,
+  /// Whether account details have been submitted.
+  /// Standard accounts cannot receive payouts before this is `true`.
+  pub details_submitted: bool,
+  /// An email address associated with the account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub email: Option<String>,
+  /// Information about the upcoming new requirements for the account, including what information needs to be collected, and by when.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub future_requirements: Option<Requirements>,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metadata: Option<HashMap<String, String>>,
+  /// Whether Stripe can send payouts to this account.
+  pub payouts_enabled: bool,
+  /// Information about the requirements for the account, including what information needs to be collected, and by when.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub requirements: Option<Requirements>,
+  /// The Stripe account type, one of `standard`, `express`, or `custom`.
+  #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+  pub r#type: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Business information about an account.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BusinessProfile {
+  /// [The merchant category code for the account](https://stripe.com/docs/connect/setting-mcc).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub mcc: Option<String>,
+  /// The customer-facing business name.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// A publicly available email address for sending support issues to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub support_email: Option<String>,
+  /// A publicly available phone number to call with support issues.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub support_phone: Option<String>,
+  /// A publicly available website for handling support issues.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub support_url: Option<String>,
+  /// The business’s publicly available website.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub url: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,15 @@
+/// Deleted account object from 10/19/2026
+/// 
+/// [Delete an account](https://stripe.com/docs/api/accounts/delete)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ResponseDeleted {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // account
+  /// Always `true` for a deleted object.
+  pub deleted: bool,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
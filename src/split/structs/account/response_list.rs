@@ -0,0 +1,15 @@
+/// Returns a list of accounts connected to your platform via Connect.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ResponseList {
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String,
+  /// The end of the requested URL of the API.
+  pub url: String,
+  /// If more than the data received now exists `true` otherwise `false`.
+  pub has_more: bool,
+  /// All received data.
+  pub data: Vec<Response>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
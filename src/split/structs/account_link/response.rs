@@ -0,0 +1,18 @@
+/// Account Link object from 10/19/2026
+/// 
+/// [Account Link object](https://stripe.com/docs/api/account_links/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // account_link
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// The timestamp at which this account link will expire.
+  pub expires_at: i64,
+  /// The URL for the account link.
+  pub url: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,42 @@
+/// Application Fee object from 10/19/2026
+/// 
+/// [Application Fee object](https://stripe.com/docs/api/application_fees/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // application_fee
+  /// ID of the Stripe account this fee was taken from.
+  pub account: String,
+  /// Amount earned, in cents.
+  pub amount: i64,
+  /// Amount in cents refunded (can be less than the amount attribute on the fee if a partial refund was issued).
+  pub amount_refunded: i64,
+  /// ID of the Connect application that earned the fee.
+  pub application: String,
+  /// Balance transaction that describes the impact of this collected application fee on your account balance (not including refunds).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub balance_transaction: Option<String>,
+  /// ID of the charge that the application fee was taken from.
+  pub charge: String,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+  /// Must be a [supported currency](https://stripe.com/docs/currencies).
+  pub currency: String,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// ID of the corresponding charge on the platform account, if this fee was the result of a charge using the `destination` parameter.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub originating_transaction: Option<String>,
+  /// Whether the fee has been fully refunded.
+  /// If the fee is only partially refunded, this attribute will still be `false`.
+  pub refunded: bool,
+  /// A list of refunds that have been applied to the fee.
+  pub refunds: crate::application_fee_refund::ResponseList,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
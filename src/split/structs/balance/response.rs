@@ -3,12 +3,14 @@
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Available {
   /// Balance amount.
-  pub amount: u32,
+  pub amount: i64,
   /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
   /// Must be a [supported currency](https://stripe.com/docs/currencies).
   pub currency: String,
   /// Breakdown of balance by source types.
-  pub source_types: HashMap<String, u32>
+  pub source_types: HashMap<String, i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Funds that are not yet available in the balance, due to the 7-day rolling pay cycle.
@@ -16,12 +18,14 @@ pub struct Available {
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Pending {
   /// Balance amount.
-  pub amount: u32,
+  pub amount: i64,
   /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
   /// Must be a [supported currency](https://stripe.com/docs/currencies).
   pub currency: String,
   /// Breakdown of balance by source types.
-  pub source_types: HashMap<String, u32>
+  pub source_types: HashMap<String, i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Funds held due to negative balances on connected Custom accounts.
@@ -29,43 +33,51 @@ pub struct Pending {
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ConnectReserved {
   /// Balance amount.
-  pub amount: u32,
+  pub amount: i64,
   /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
   /// Must be a [supported currency](https://stripe.com/docs/currencies).
   pub currency: String,
   /// Breakdown of balance by source types.
-  pub source_types: HashMap<String, u32>
+  pub source_types: HashMap<String, i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Funds that can be paid out using Instant Payouts.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct InstantAvailable {
   /// Balance amount.
-  pub amount: u32,
+  pub amount: i64,
   /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
   /// Must be a [supported currency](https://stripe.com/docs/currencies).
   pub currency: String,
   /// Breakdown of balance by source types.
-  pub source_types: HashMap<String, u32>
+  pub source_types: HashMap<String, i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Funds that are available for use.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct IssuingAvailable {
   /// Balance amount.
-  pub amount: u32,
+  pub amount: i64,
   /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
   /// Must be a [supported currency](https://stripe.com/docs/currencies).
   pub currency: String,
   /// Breakdown of balance by source types.
-  pub source_types: HashMap<String, u32>
+  pub source_types: HashMap<String, i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Funds that can be spent on your [Issued Cards](https://stripe.com/docs/api/balance/balance_object#issuing/cards).
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Issuing {
   /// Funds that are available for use.
-  pub available: Vec<IssuingAvailable>
+  pub available: Vec<IssuingAvailable>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Balance object from 01/12/2023
@@ -93,5 +105,24 @@ pub struct Response {
   pub instant_available: Option<Vec<InstantAvailable>>,
   /// Funds that can be spent on your [Issued Cards](https://stripe.com/docs/api/balance/balance_object#issuing/cards).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub issuing: Option<Issuing>
-}
\ No newline at end of file
+  pub issuing: Option<Issuing>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+impl Response {
+  /// Returns the amount (in cents) that can be paid out with Instant Payouts in `currency`.
+  /// 
+  /// If `source_type` is set, only the funds of this source type (e.g. `card`) are counted.
+  pub fn instant_available_amount(&self, currency: &str, source_type: Option<&str>) -> i64 {
+    self.instant_available
+      .iter()
+      .flatten()
+      .filter(|r| r.currency.eq_ignore_ascii_case(currency))
+      .map(|r| match source_type {
+        Some(t) => r.source_types.get(t).copied().unwrap_or_default(),
+        None => r.amount
+      })
+      .sum()
+  }
+}
@@ -0,0 +1,90 @@
+/// The summed amounts (in cents) of balance transactions.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct Totals {
+  /// The number of balance transactions.
+  pub count: usize,
+  /// Sum of the gross amounts.
+  pub gross: i64,
+  /// Sum of the fees.
+  pub fee: i64,
+  /// Sum of the net amounts.
+  pub net: i64
+}
+
+impl Totals {
+  fn add(&mut self, transaction: &Response) {
+    self.count += 1;
+    self.gross += transaction.amount;
+    self.fee += transaction.fee;
+    self.net += transaction.net;
+  }
+}
+
+/// All balance transactions of a payout that share the same `reporting_category`.
+#[derive(Clone, Debug)]
+pub struct Category {
+  /// The [reporting category](https://stripe.com/docs/reports/reporting-categories), e.g. `charge` or `refund`.
+  pub reporting_category: String,
+  /// The totals of all balance transactions in this category.
+  pub totals: Totals,
+  /// The balance transactions in this category.
+  pub transactions: Vec<Response>
+}
+
+/// The balance transactions that were paid out with a payout, grouped by `reporting_category`.
+/// 
+/// Created with `Client::payout_reconciliation()`.
+#[derive(Clone, Debug)]
+pub struct Reconciliation {
+  /// The unique ID of the payout.
+  pub payout: String,
+  /// The balance transaction of the payout itself (type `payout`).
+  pub payout_transaction: Option<Response>,
+  /// The paid out balance transactions grouped by `reporting_category`, sorted by name.
+  pub categories: Vec<Category>,
+  /// The totals of all paid out balance transactions.
+  /// `net` is the amount of the payout.
+  pub totals: Totals
+}
+
+impl Reconciliation {
+  pub(crate) fn create(payout: String, transactions: Vec<Response>) -> Self {
+    let mut result = Self {
+      payout,
+      payout_transaction: None,
+      categories: Vec::new(),
+      totals: Totals::default()
+    };
+
+    for r in transactions {
+      if r.r#type == "payout" {
+        result.payout_transaction = Some(r);
+        continue;
+      }
+
+      result.totals.add(&r);
+      let index = match result.categories.binary_search_by(|c| c.reporting_category.cmp(&r.reporting_category)) {
+        Ok(i) => i,
+        Err(i) => {
+          result.categories.insert(i, Category { reporting_category: r.reporting_category.clone(), totals: Totals::default(), transactions: Vec::new() });
+          i
+        }
+      };
+      result.categories[index].totals.add(&r);
+      result.categories[index].transactions.push(r);
+    }
+
+    result
+  }
+
+  /// Returns the category with the given `reporting_category`.
+  pub fn category(&self, reporting_category: &str) -> Option<&Category> {
+    self.categories.iter().find(|r| r.reporting_category == reporting_category)
+  }
+
+  /// Returns `true` if the net total equals the `amount` of `payout`.
+  #[cfg(feature = "payout")]
+  pub fn matches(&self, payout: &crate::payout::Response) -> bool {
+    self.totals.net == payout.amount as i64
+  }
+}
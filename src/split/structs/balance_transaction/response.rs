@@ -4,15 +4,39 @@ pub struct FeeDetails {
   /// Amount of the fee, in cents.
   pub amount: u32,
   /// ID of the Connect application that earned the fee.
-  pub application: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub application: Option<String>,
   /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
   /// Must be a [supported currency](https://stripe.com/docs/currencies).
   pub currency: String,
   /// An arbitrary string attached to the object.
   /// Often useful for displaying to users.
-  pub description: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
   /// Type of the fee, one of: `application_fee`, `stripe_fee` or `tax`.
-  pub r#type: String
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The Stripe object to which a balance transaction is related.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum Source {
+  /// The ID of the object.
+  Id(String),
+  /// The expanded object, e.g. a charge or a refund.
+  Object(serde_json::Map<String, serde_json::Value>)
+}
+
+impl Source {
+  /// Returns the ID of the object.
+  pub fn id(&self) -> Option<&str> {
+    match self {
+      Self::Id(r) => Some(r),
+      Self::Object(r) => r.get("id").and_then(|r| r.as_str())
+    }
+  }
 }
 
 /// Balance transactions represent funds moving through your Stripe account.
@@ -24,7 +48,7 @@ pub struct Response {
   /// String representing the object’s type. Objects of the same type share the same value.
   pub object: String, // balance_transaction
   /// Gross amount of the transaction, in cents.
-  pub amount: u32,
+  pub amount: i64,
   /// The date the transaction’s net funds will become available in the Stripe balance.
   pub available_on: i64,
   /// Time at which the object was created.
@@ -35,22 +59,28 @@ pub struct Response {
   pub currency: String,
   /// An arbitrary string attached to the object. Often useful for displaying to users.
   pub description: Option<String>,
-  pub exchange_rate: Option<u32>,
+  /// The exchange rate used, if applicable, for this transaction.
+  /// Specifically, if money was converted from currency A to currency B, then the `amount` in currency A, times `exchange_rate`, would be the `amount` in currency B.
+  pub exchange_rate: Option<f64>,
   /// Fees (in cents) paid for this transaction.
-  pub fee: u32,
+  pub fee: i64,
   /// Detailed breakdown of fees (in cents) paid for this transaction.
   pub fee_details: Vec<FeeDetails>,
   /// Net amount of the transaction, in cents.
-  pub net: u32,
+  pub net: i64,
   /// [Learn more](https://stripe.com/docs/reports/reporting-categories) about how reporting categories can help you understand balance transactions from an accounting perspective.
   pub reporting_category: String,
   /// The Stripe object to which this transaction is related.
-  pub source: String,
+  /// Only contains the ID unless it was expanded with `expand[]=source`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub source: Option<Source>,
   /// If the transaction’s net funds are available in the Stripe balance yet.
   /// Either `available` or `pending`.
   pub status: String,
   /// Transaction type: `adjustment`, `advance`, `advance_funding`, `anticipation_repayment`, `application_fee`, `application_fee_refund`, `charge`, `connect_collection_transfer`, `contribution`, `issuing_authorization_hold`, `issuing_authorization_release`, `issuing_dispute`, `issuing_transaction`, `payment`, `payment_failure_refund`, `payment_refund`, `payout`, `payout_cancel`, `payout_failure`, `refund`, `refund_failure`, `reserve_transaction`, `reserved_funds`, `stripe_fee`, `stripe_fx_fee`, `tax_fee`, `topup`, `topup_reversal`, `transfer`, `transfer_cancel`, `transfer_failure`, or `transfer_refund`.
   /// [Learn more](https://stripe.com/docs/reports/balance-transaction-types) about balance transaction types and what they represent.
   /// If you are looking to classify transactions for accounting purposes, you might want to consider `reporting_category` instead.
-  pub r#type: String
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
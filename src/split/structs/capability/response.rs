@@ -0,0 +1,29 @@
+/// Capability object from 10/19/2026
+/// 
+/// [Capability object](https://stripe.com/docs/api/capabilities/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// The identifier for the capability, e.g. `card_payments`.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // capability
+  /// The account for which the capability enables functionality.
+  pub account: String,
+  /// Information about the upcoming new requirements for the capability, including what information needs to be collected, and by when.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub future_requirements: Option<crate::account::Requirements>,
+  /// Whether the capability has been requested.
+  pub requested: bool,
+  /// Time at which the capability was requested.
+  /// Measured in seconds since the Unix epoch.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub requested_at: Option<i64>,
+  /// Information about the requirements for the capability, including what information needs to be collected, and by when.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub requirements: Option<crate::account::Requirements>,
+  /// The status of the capability, one of `active`, `disabled`, `inactive`, `pending`, or `unrequested`.
+  pub status: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
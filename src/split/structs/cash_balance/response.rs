@@ -0,0 +1,32 @@
+/// The settings of a cash balance.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Settings {
+  /// The configuration for how funds that land in the customer cash balance are reconciled, either `automatic` or `manual`.
+  pub reconciliation_mode: String,
+  /// A flag to indicate if reconciliation mode returned is the user’s default or is specific to this customer cash balance.
+  pub using_merchant_default: bool,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Cash Balance object from 10/19/2026
+/// 
+/// [Cash Balance object](https://stripe.com/docs/api/cash_balance/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // cash_balance
+  /// A hash of all cash balances available to this customer.
+  /// Keys are currencies, and values are amounts, in cents.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub available: Option<HashMap<String, i64>>,
+  /// The ID of the customer whose cash balance this object represents.
+  pub customer: String,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// The settings of the cash balance.
+  pub settings: Settings,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,81 @@
+/// Details of a cash balance transaction that references a PaymentIntent.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentIntentDetails {
+  /// The PaymentIntent the funds were applied to or unapplied from.
+  pub payment_intent: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Details of a cash balance transaction that references a Refund.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RefundDetails {
+  /// The Refund that moved these funds into the customer’s cash balance.
+  pub refund: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The bank transfer that funded a cash balance.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BankTransfer {
+  /// The user-supplied reference field on the bank transfer.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub reference: Option<String>,
+  /// The funding method type used to fund the customer balance, e.g. `eu_bank_transfer` or `us_bank_transfer`.
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Details of a cash balance transaction of type `funded`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Funded {
+  /// The bank transfer that funded the cash balance.
+  pub bank_transfer: BankTransfer,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Cash Balance Transaction object from 10/19/2026
+/// 
+/// [Cash Balance Transaction object](https://stripe.com/docs/api/cash_balance_transactions/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // customer_cash_balance_transaction
+  /// If this is a `type=applied_to_payment` transaction, contains information about how funds were applied.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub applied_to_payment: Option<PaymentIntentDetails>,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+  /// Must be a [supported currency](https://stripe.com/docs/currencies).
+  pub currency: String,
+  /// The customer whose available cash balance changed as a result of this transaction.
+  pub customer: String,
+  /// The total available cash balance for the specified currency after this transaction was applied, in cents.
+  pub ending_balance: i64,
+  /// If this is a `type=funded` transaction, contains information about the funding.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub funded: Option<Funded>,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// The amount by which the cash balance changed, in cents.
+  /// A positive value indicates funds being added to the cash balance, a negative value indicates funds being removed from the cash balance.
+  pub net_amount: i64,
+  /// If this is a `type=refunded_from_payment` transaction, contains information about the source of the refund.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub refunded_from_payment: Option<RefundDetails>,
+  /// The type of the cash balance transaction: `adjusted_for_overdraft`, `applied_to_payment`, `funded`, `funding_reversed`, `refunded_from_payment`, `return_canceled`, `return_initiated`, `transferred_to_balance`, or `unapplied_from_payment`.
+  pub r#type: String,
+  /// If this is a `type=unapplied_from_payment` transaction, contains information about how funds were unapplied.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unapplied_from_payment: Option<PaymentIntentDetails>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
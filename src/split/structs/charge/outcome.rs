@@ -0,0 +1,53 @@
+/// Details about whether the payment was accepted, and why.
+/// See [understanding declines](https://stripe.com/docs/declines) for details.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Outcome {
+  /// Possible values are `approved_by_network`, `declined_by_network`, `not_sent_to_network`, and `reversed_after_approval`.
+  /// The value `reversed_after_approval` indicates the payment was [blocked by Stripe](https://stripe.com/docs/declines#blocked-payments) after bank authorization, and may temporarily appear as “pending” on a cardholder’s statement.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub network_status: Option<String>,
+  /// An enumerated value providing a more detailed explanation of the outcome’s `type`.
+  /// Charges blocked by Radar’s default block rule have the value `highest_risk_level`.
+  /// Charges placed in review by Radar’s default review rule have the value `elevated_risk_level`.
+  /// Charges authorized, blocked, or placed in review by custom rules have the value `rule`.
+  /// See [understanding declines](https://stripe.com/docs/declines) for more details.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub reason: Option<String>,
+  /// Stripe Radar’s evaluation of the riskiness of the payment.
+  /// Possible values for evaluated payments are `normal`, `elevated`, `highest`.
+  /// For non-card payments, and card-based payments predating the public assignment of risk levels, this field will have the value `not_assessed`.
+  /// In the event of an error in the evaluation, this field will have the value `unknown`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub risk_level: Option<String>,
+  /// Stripe Radar’s evaluation of the riskiness of the payment.
+  /// Possible values for evaluated payments are between 0 and 100.
+  /// For non-card payments, card-based payments predating the public assignment of risk scores, or in the event of an error during evaluation, this field will not be present.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub risk_score: Option<u32>,
+  /// The ID of the Radar rule that matched the payment, if applicable.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub rule: Option<String>,
+  /// A human-readable description of the outcome type and reason, designed for you (the recipient of the payment), not your customer.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub seller_message: Option<String>,
+  /// Possible values are `authorized`, `manual_review`, `issuer_declined`, `blocked`, and `invalid`.
+  /// See [understanding declines](https://stripe.com/docs/declines) and [Radar reviews](https://stripe.com/docs/radar/reviews) for details.
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Information on fraud assessments for the charge.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct FraudDetails {
+  /// Assessments from Stripe.
+  /// If set, the value is `fraudulent`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub stripe_report: Option<String>,
+  /// Assessments reported by you.
+  /// If set, possible values of are `safe` and `fraudulent`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub user_report: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
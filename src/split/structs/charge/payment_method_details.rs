@@ -0,0 +1,207 @@
+/// Check results by Card networks on Card address and CVC at time of payment.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentMethodDetailsCardChecks {
+  /// If a address line1 was provided, results of the check, one of `pass`, `fail`, `unavailable`, or `unchecked`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub address_line1_check: Option<String>,
+  /// If a address postal code was provided, results of the check, one of `pass`, `fail`, `unavailable`, or `unchecked`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub address_postal_code_check: Option<String>,
+  /// If a CVC was provided, results of the check, one of `pass`, `fail`, `unavailable`, or `unchecked`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cvc_check: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Populated if this transaction used 3D Secure authentication.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentMethodDetailsCardThreeDSecure {
+  /// For authenticated transactions: how the customer was authenticated by the issuing bank.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub authentication_flow: Option<String>,
+  /// Indicates the outcome of 3D Secure authentication.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub result: Option<String>,
+  /// Additional information about why 3D Secure succeeded or failed based on the `result`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub result_reason: Option<String>,
+  /// The version of 3D Secure that was used.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub version: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// If this Card is part of a card wallet, this contains the details of the card wallet.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentMethodDetailsCardWallet {
+  /// (For tokenized numbers only.) The last four digits of the device account number.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub dynamic_last4: Option<String>,
+  /// The type of the card wallet, one of `amex_express_checkout`, `apple_pay`, `google_pay`, `masterpass`, `samsung_pay`, `visa_checkout`, or `link`.
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// If this is a `card` payment, this hash contains a snapshot of the transaction specific details of the `card` payment method.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentMethodDetailsCard {
+  /// Card brand.
+  /// Can be `amex`, `diners`, `discover`, `jcb`, `mastercard`, `unionpay`, `visa`, or `unknown`.
+  pub brand: String,
+  /// Check results by Card networks on Card address and CVC at time of payment.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub checks: Option<PaymentMethodDetailsCardChecks>,
+  /// Two-letter ISO code representing the country of the card.
+  /// You could use this attribute to get a sense of the international breakdown of cards you’ve collected.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub country: Option<String>,
+  /// Two-digit number representing the card’s expiration month.
+  pub exp_month: u8,
+  /// Four-digit number representing the card’s expiration year.
+  pub exp_year: u16,
+  /// Uniquely identifies this particular card number.
+  /// You can use this attribute to check whether two customers who’ve signed up with you are using the same card number, for example.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fingerprint: Option<String>,
+  /// Card funding type.
+  /// Can be `credit`, `debit`, `prepaid`, or `unknown`.
+  pub funding: String,
+  /// The last four digits of the card.
+  pub last4: String,
+  /// ID of the mandate used to make this payment or created by it.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub mandate: Option<String>,
+  /// Identifies which network this charge was processed on.
+  /// Can be `amex`, `cartes_bancaires`, `diners`, `discover`, `interac`, `jcb`, `mastercard`, `unionpay`, `visa`, or `unknown`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub network: Option<String>,
+  /// Populated if this transaction used 3D Secure authentication.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub three_d_secure: Option<PaymentMethodDetailsCardThreeDSecure>,
+  /// If this Card is part of a card wallet, this contains the details of the card wallet.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub wallet: Option<PaymentMethodDetailsCardWallet>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// If this is a `sepa_debit` payment, this hash contains a snapshot of the transaction specific details of the `sepa_debit` payment method.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentMethodDetailsSepaDebit {
+  /// Bank code of bank associated with the bank account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub bank_code: Option<String>,
+  /// Branch code of bank associated with the bank account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub branch_code: Option<String>,
+  /// Two-letter ISO code representing the country the bank account is located in.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub country: Option<String>,
+  /// Uniquely identifies this particular bank account.
+  /// You can use this attribute to check whether two bank accounts are the same.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fingerprint: Option<String>,
+  /// Last four characters of the IBAN.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub last4: Option<String>,
+  /// ID of the mandate used to make this payment.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub mandate: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// If this is a `us_bank_account` payment, this hash contains a snapshot of the transaction specific details of the `us_bank_account` payment method.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentMethodDetailsUsBankAccount {
+  /// Account holder type: `individual` or `company`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub account_holder_type: Option<String>,
+  /// Account type: `checkings` or `savings`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub account_type: Option<String>,
+  /// Name of the bank associated with the bank account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub bank_name: Option<String>,
+  /// Uniquely identifies this particular bank account.
+  /// You can use this attribute to check whether two bank accounts are the same.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fingerprint: Option<String>,
+  /// Last four digits of the bank account number.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub last4: Option<String>,
+  /// Routing number of the bank account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub routing_number: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// If this is a bank redirect payment (e.g. `ideal`, `sofort`, `bancontact`, `giropay` or `eps`), this hash contains a snapshot of the transaction specific details of the payment method.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentMethodDetailsBankRedirect {
+  /// The customer’s bank.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub bank: Option<String>,
+  /// Bank code of bank associated with the bank account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub bank_code: Option<String>,
+  /// Name of the bank associated with the bank account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub bank_name: Option<String>,
+  /// Bank Identifier Code of the bank associated with the bank account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub bic: Option<String>,
+  /// Two-letter ISO code representing the country the bank account is located in.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub country: Option<String>,
+  /// Last four characters of the IBAN.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub iban_last4: Option<String>,
+  /// Owner’s verified full name.
+  /// Values are verified or provided by the bank directly (if supported) at the time of authorization or settlement.
+  /// They cannot be set or mutated.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub verified_name: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Details about the payment method at the time of the transaction.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentMethodDetails {
+  /// If this is a `bancontact` payment, this hash contains a snapshot of the transaction specific details of the `bancontact` payment method.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub bancontact: Option<PaymentMethodDetailsBankRedirect>,
+  /// If this is a `card` payment, this hash contains a snapshot of the transaction specific details of the `card` payment method.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub card: Option<PaymentMethodDetailsCard>,
+  /// If this is a `eps` payment, this hash contains a snapshot of the transaction specific details of the `eps` payment method.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub eps: Option<PaymentMethodDetailsBankRedirect>,
+  /// If this is a `giropay` payment, this hash contains a snapshot of the transaction specific details of the `giropay` payment method.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub giropay: Option<PaymentMethodDetailsBankRedirect>,
+  /// If this is a `ideal` payment, this hash contains a snapshot of the transaction specific details of the `ideal` payment method.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub ideal: Option<PaymentMethodDetailsBankRedirect>,
+  /// If this is a `sepa_debit` payment, this hash contains a snapshot of the transaction specific details of the `sepa_debit` payment method.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sepa_debit: Option<PaymentMethodDetailsSepaDebit>,
+  /// If this is a `sofort` payment, this hash contains a snapshot of the transaction specific details of the `sofort` payment method.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sofort: Option<PaymentMethodDetailsBankRedirect>,
+  /// If this is a `us_bank_account` payment, this hash contains a snapshot of the transaction specific details of the `us_bank_account` payment method.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub us_bank_account: Option<PaymentMethodDetailsUsBankAccount>,
+  /// The type of transaction-specific details of the payment method used in the payment, one of `ach_credit_transfer`, `ach_debit`, `acss_debit`, `alipay`, `au_becs_debit`, `bancontact`, `card`, `card_present`, `eps`, `giropay`, `ideal`, `klarna`, `multibanco`, `p24`, `sepa_debit`, `sofort`, `stripe_account`, or `wechat`.
+  /// An additional hash is included on `payment_method_details` with a name matching this value.
+  /// It contains information specific to the payment method.
+  pub r#type: String,
+  /// The details of all payment methods that are not (yet) part of this struct.
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,218 @@
+include!("billing_details.rs");
+
+include!("outcome.rs");
+
+include!("payment_method_details.rs");
+
+/// The data with which to automatically create a Transfer when the payment is finalized.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TransferData {
+  /// The amount transferred to the destination account, if specified.
+  /// By default, the entire charge amount is transferred to the destination account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub amount: Option<u32>,
+  /// ID of an existing, connected Stripe account to transfer funds to if `transfer_data` was specified in the charge request.
+  pub destination: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Charge object from 10/19/2026
+/// 
+/// [Charge object](https://stripe.com/docs/api/charges/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // charge
+  /// Amount intended to be collected by this payment.
+  /// A positive integer representing how much to charge in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal) (e.g., 100 cents to charge $1.00 or 100 to charge ¥100, a zero-decimal currency).
+  pub amount: u32,
+  /// Amount in cents captured (can be less than the amount attribute on the charge if a partial capture was made).
+  pub amount_captured: u32,
+  /// Amount in cents refunded (can be less than the amount attribute on the charge if a partial refund was issued).
+  pub amount_refunded: u32,
+  /// ID of the Connect application that created the charge.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub application: Option<String>,
+  /// The application fee (if any) for the charge.
+  /// [See the Connect documentation](https://stripe.com/docs/connect/direct-charges#collecting-fees) for details.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub application_fee: Option<String>,
+  /// The amount of the application fee (if any) requested for the charge.
+  /// [See the Connect documentation](https://stripe.com/docs/connect/direct-charges#collecting-fees) for details.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub application_fee_amount: Option<u32>,
+  /// ID of the balance transaction that describes the impact of this charge on your account balance (not including refunds or disputes).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub balance_transaction: Option<String>,
+  /// Billing information associated with the payment method at the time of the transaction.
+  pub billing_details: BillingDetails,
+  /// The full statement descriptor that is passed to card networks, and that is displayed on your customers’ credit card and bank statements.
+  /// Allows you to see what the statement descriptor looks like after the static and dynamic portions are combined.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub calculated_statement_descriptor: Option<String>,
+  /// If the charge was created without capturing, this Boolean represents whether it is still uncaptured or has since been captured.
+  pub captured: bool,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+  /// Must be a [supported currency](https://stripe.com/docs/currencies).
+  pub currency: String,
+  /// ID of the customer this charge is for if one exists.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer: Option<String>,
+  /// An arbitrary string attached to the object.
+  /// Often useful for displaying to users.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// Whether the charge has been disputed.
+  pub disputed: bool,
+  /// ID of the balance transaction that describes the reversal of the balance on your account due to payment failure.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub failure_balance_transaction: Option<String>,
+  /// Error code explaining reason for charge failure if available (see [the errors section](https://stripe.com/docs/error-codes) for a list of codes).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub failure_code: Option<String>,
+  /// Message to user further explaining reason for charge failure if available.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub failure_message: Option<String>,
+  /// Information on fraud assessments for the charge.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fraud_details: Option<FraudDetails>,
+  /// ID of the invoice this charge is for if one exists.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub invoice: Option<String>,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  pub metadata: HashMap<String, String>,
+  /// The account (if any) the charge was made on behalf of without triggering an automatic transfer.
+  /// See the [Connect documentation](https://stripe.com/docs/connect/separate-charges-and-transfers) for details.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub on_behalf_of: Option<String>,
+  /// Details about whether the payment was accepted, and why.
+  /// See [understanding declines](https://stripe.com/docs/declines) for details.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub outcome: Option<Outcome>,
+  /// `true` if the charge succeeded, or was successfully authorized for later capture.
+  pub paid: bool,
+  /// ID of the PaymentIntent associated with this charge, if one exists.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_intent: Option<String>,
+  /// ID of the payment method used in this charge.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_method: Option<String>,
+  /// Details about the payment method at the time of the transaction.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_method_details: Option<PaymentMethodDetails>,
+  /// This is the email address that the receipt for this charge was sent to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub receipt_email: Option<String>,
+  /// This is the transaction number that appears on email receipts sent for this charge.
+  /// This attribute will be `null` until a receipt has been sent.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub receipt_number: Option<String>,
+  /// This is the URL to view the receipt for this charge.
+  /// The receipt is kept up-to-date to the latest state of the charge, including any refunds.
+  /// If the charge is for an Invoice, the receipt will be stylized as an Invoice receipt.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub receipt_url: Option<String>,
+  /// Whether the charge has been fully refunded.
+  /// If the charge is only partially refunded, this attribute will still be false.
+  pub refunded: bool,
+  /// A list of refunds that have been applied to the charge.
+  /// Only included if you [expand](https://stripe.com/docs/api/expanding_objects) `refunds`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub refunds: Option<crate::refund::ResponseList>,
+  /// ID of the review associated with this charge if one exists.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub review: Option<String>,
+  /// Shipping information for the charge.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub shipping: Option<Shipping>,
+  /// The transfer ID which created this charge.
+  /// Only present if the charge came from another Stripe account.
+  /// [See the Connect documentation](https://stripe.com/docs/connect/destination-charges) for details.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub source_transfer: Option<String>,
+  /// For card charges, use `statement_descriptor_suffix` instead.
+  /// Otherwise, you can use this value as the complete description of a charge on your customers’ statements.
+  /// Must contain at least one letter, maximum 22 characters.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub statement_descriptor: Option<String>,
+  /// Provides information about the charge that customers see on their statements.
+  /// Concatenated with the prefix (shortened descriptor) or statement descriptor that’s set on the account to form the complete statement descriptor.
+  /// Maximum 22 characters for the concatenated descriptor.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub statement_descriptor_suffix: Option<String>,
+  /// The status of the payment is either `succeeded`, `pending`, or `failed`.
+  pub status: String,
+  /// ID of the transfer to the `destination` account (only applicable if the charge was created using the `destination` parameter).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub transfer: Option<String>,
+  /// An optional dictionary including the account to automatically transfer to as part of a destination charge.
+  /// [See the Connect documentation](https://stripe.com/docs/connect/destination-charges) for details.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub transfer_data: Option<TransferData>,
+  /// A string that identifies this transaction as part of a group.
+  /// See the [Connect documentation](https://stripe.com/docs/connect/separate-charges-and-transfers#transfer-options) for details.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub transfer_group: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+impl Response {
+  /// Returns an `Info` to retrieve the `crate::application_fee::Response` collected with this charge.
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(r) = client.retrieve_charge("CHARGE_ID".to_string()).get().await {
+  ///     if let Some(info) = r.retrieve_application_fee(&client) {
+  ///       let stripe_response = info.get().await;
+  ///     }
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  #[cfg(feature = "application_fee")]
+  pub fn retrieve_application_fee<'a>(&self, client: &'a crate::Client) -> Option<crate::application_fee::Info<'a>> {
+    self.application_fee
+      .as_ref()
+      .map(|r| client.retrieve_application_fee(r.clone()))
+  }
+
+  /// Returns an `Info` to retrieve the `crate::transfer::Response` to the `destination` account of this charge.
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(r) = client.retrieve_charge("CHARGE_ID".to_string()).get().await {
+  ///     if let Some(info) = r.retrieve_transfer(&client) {
+  ///       let stripe_response = info.get().await;
+  ///     }
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  #[cfg(feature = "transfer")]
+  pub fn retrieve_transfer<'a>(&self, client: &'a crate::Client) -> Option<crate::transfer::Info<'a>> {
+    self.transfer
+      .as_ref()
+      .map(|r| client.retrieve_transfer(r.clone()))
+  }
+}
@@ -0,0 +1,21 @@
+/// Search for charges you’ve previously created using Stripe’s [Search Query Language](https://stripe.com/docs/search#search-query-language).
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ResponseSearch {
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // search_result
+  /// The end of the requested URL of the API.
+  pub url: String,
+  /// If more than the data received now exists `true` otherwise `false`.
+  pub has_more: bool,
+  /// All received data.
+  pub data: Vec<Response>,
+  /// The cursor for the next page, pass it as `page` to receive the next results.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub next_page: Option<String>,
+  /// The total number of objects that match the query, only accurate up to 10,000.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub total_count: Option<u64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
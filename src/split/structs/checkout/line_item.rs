@@ -0,0 +1,36 @@
+/// Checkout Session line item object from 10/19/2026
+/// 
+/// [Line item object](https://stripe.com/docs/api/checkout/sessions/line_items)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LineItem {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // item
+  /// Total discount amount applied.
+  /// If no discounts were applied, defaults to 0.
+  pub amount_discount: i64,
+  /// Total before any discounts or taxes are applied.
+  pub amount_subtotal: i64,
+  /// Total tax amount applied.
+  /// If no tax was applied, defaults to 0.
+  pub amount_tax: i64,
+  /// Total after discounts and taxes.
+  pub amount_total: i64,
+  /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+  /// Must be a [supported currency](https://stripe.com/docs/currencies).
+  pub currency: String,
+  /// An arbitrary string attached to the object.
+  /// Defaults to product name.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// The price used to generate the line item.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub price: Option<crate::price::Response>,
+  /// The quantity of products being purchased.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub quantity: Option<u64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
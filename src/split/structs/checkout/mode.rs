@@ -0,0 +1,22 @@
+/// The mode of a Checkout Session.
+/// 
+/// Use it with `format!` to create the body of a request, e.g. `format!("mode={};", Mode::Payment)`.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Mode {
+  /// Accept one-time payments for cards, iDEAL, and more.
+  Payment,
+  /// Save payment details to charge your customers later.
+  Setup,
+  /// Use Stripe Billing to set up fixed-price subscriptions.
+  Subscription,
+  /// A mode that is not (yet) part of this enumeration.
+  #[serde(other)]
+  Unknown
+}
+
+impl std::fmt::Display for Mode {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+  }
+}
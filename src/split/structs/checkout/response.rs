@@ -0,0 +1,159 @@
+include!("line_item.rs");
+
+include!("mode.rs");
+
+/// Checkout Session object from 10/19/2026
+/// 
+/// [Checkout Session object](https://stripe.com/docs/api/checkout/sessions/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // checkout.session
+  /// Enables user redeemable promotion codes.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub allow_promotion_codes: Option<bool>,
+  /// Total of all items before discounts or taxes are applied.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub amount_subtotal: Option<i64>,
+  /// Total of all items after discounts and taxes are applied.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub amount_total: Option<i64>,
+  /// Describes whether Checkout should collect the customer’s billing address, either `auto` or `required`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub billing_address_collection: Option<String>,
+  /// If set, Checkout displays a back button and customers will be directed to this URL if they decide to cancel payment and return to your website.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cancel_url: Option<String>,
+  /// A unique string to reference the Checkout Session.
+  /// This can be a customer ID, a cart ID, or similar, and can be used to reconcile the Session with your internal systems.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub client_reference_id: Option<String>,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+  /// Must be a [supported currency](https://stripe.com/docs/currencies).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub currency: Option<String>,
+  /// The ID of the customer for this Session.
+  /// For Checkout Sessions in `payment` or `subscription` mode, Checkout will create a new customer object based on information provided during the payment flow unless an existing customer was provided when the Session was created.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer: Option<String>,
+  /// Configure whether a Checkout Session creates a Customer when the Checkout Session completes, either `always` or `if_required`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer_creation: Option<String>,
+  /// The customer details including the customer’s tax exempt status and the customer’s tax IDs.
+  /// Only the customer’s email is present on Sessions in `setup` mode.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer_details: Option<CustomerDetails>,
+  /// If provided, this value will be used when the Customer object is created.
+  /// If not provided, customers will be asked to enter their email address.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer_email: Option<String>,
+  /// The timestamp at which the Checkout Session will expire.
+  pub expires_at: i64,
+  /// ID of the invoice created by the Checkout Session, if it exists.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub invoice: Option<String>,
+  /// The line items purchased by the customer.
+  /// Only included if `expand[]=line_items` was part of the request, otherwise use `list_checkout_session_line_items()`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub line_items: Option<ResponseLineItems>,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// The IETF language tag of the locale Checkout is displayed in.
+  /// If blank or `auto`, the browser’s locale is used.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub locale: Option<String>,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metadata: Option<HashMap<String, String>>,
+  /// The mode of the Checkout Session, see `Mode`.
+  pub mode: Mode,
+  /// The ID of the PaymentIntent for Checkout Sessions in `payment` mode.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_intent: Option<String>,
+  /// The ID of the Payment Link that created this Session.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_link: Option<String>,
+  /// Configure whether a Checkout Session should collect a payment method, either `always` or `if_required`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_method_collection: Option<String>,
+  /// A list of the types of payment methods (e.g. card) this Checkout Session is allowed to accept.
+  pub payment_method_types: Vec<String>,
+  /// The payment status of the Checkout Session, one of `paid`, `unpaid`, or `no_payment_required`.
+  /// You can use this value to decide when to fulfill your customer’s order.
+  pub payment_status: String,
+  /// The ID of the original expired Checkout Session that triggered the recovery flow.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub recovered_from: Option<String>,
+  /// The ID of the SetupIntent for Checkout Sessions in `setup` mode.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub setup_intent: Option<String>,
+  /// The status of the Checkout Session, one of `open`, `complete`, or `expired`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub status: Option<String>,
+  /// Describes the type of transaction being performed by Checkout in order to customize relevant text on the page, such as the submit button.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub submit_type: Option<String>,
+  /// The ID of the subscription for Checkout Sessions in `subscription` mode.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub subscription: Option<String>,
+  /// The URL the customer will be directed to after the payment or subscription creation is successful.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub success_url: Option<String>,
+  /// The URL to the Checkout Session.
+  /// Redirect customers to this URL to take them to Checkout.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub url: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The customer details of a Checkout Session.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CustomerDetails {
+  /// The email associated with the Customer, if one exists, on the Checkout Session after a completed Checkout Session or at time of session expiry.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub email: Option<String>,
+  /// The customer’s name after a completed Checkout Session.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// The customer’s phone number after a completed Checkout Session.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub phone: Option<String>,
+  /// The customer’s tax exempt status after a completed Checkout Session, one of `exempt`, `none`, or `reverse`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tax_exempt: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+impl Response {
+  /// Returns an `Info` to retrieve the `crate::payment_intent::Response` of this Session (only in `payment` mode).
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(r) = client.retrieve_checkout_session("CHECKOUT_SESSION_ID".to_string()).get().await {
+  ///     if let Some(payment_intent) = r.retrieve_payment_intent(&client) {
+  ///       let stripe_response = payment_intent.get().await;
+  ///     }
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_payment_intent<'a>(&self, client: &'a crate::Client) -> Option<crate::payment_intent::Info<'a>> {
+    self.payment_intent
+      .as_ref()
+      .map(|r| client.retrieve_payment_intent(r.clone()))
+  }
+}
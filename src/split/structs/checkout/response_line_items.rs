@@ -0,0 +1,15 @@
+/// The line items of a Checkout Session.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ResponseLineItems {
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String,
+  /// The end of the requested URL of the API.
+  pub url: String,
+  /// If more than the data received now exists `true` otherwise `false`.
+  pub has_more: bool,
+  /// All received data.
+  pub data: Vec<LineItem>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,46 @@
+/// Customer Balance Transaction object from 10/19/2026
+/// 
+/// [Customer Balance Transaction object](https://stripe.com/docs/api/customer_balance_transactions/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // customer_balance_transaction
+  /// The amount of the transaction, in cents.
+  /// A negative value is a credit for the customer’s balance, and a positive value is a debit to the customer’s `balance`.
+  pub amount: i64,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// The ID of the credit note (if any) related to the transaction.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub credit_note: Option<String>,
+  /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+  /// Must be a [supported currency](https://stripe.com/docs/currencies).
+  pub currency: String,
+  /// The ID of the customer the transaction belongs to.
+  pub customer: String,
+  /// An arbitrary string attached to the object.
+  /// Often useful for displaying to users.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// The customer’s `balance` after the transaction was applied.
+  /// A negative value decreases the amount due on the customer’s next invoice.
+  /// A positive value increases the amount due on the customer’s next invoice.
+  pub ending_balance: i64,
+  /// The ID of the invoice (if any) related to the transaction.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub invoice: Option<String>,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metadata: Option<HashMap<String, String>>,
+  /// Transaction type: `adjustment`, `applied_to_invoice`, `credit_note`, `initial`, `invoice_overpaid`, `invoice_too_large`, `invoice_too_small`, `unspent_receiver_credit`, or `unapplied_from_invoice`.
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -3,7 +3,8 @@
 pub struct EvidenceDetails {
   /// Date by which evidence must be submitted in order to successfully challenge dispute.
   /// Will be null if the customer’s bank or credit card company doesn’t allow a response for this particular dispute.
-  pub due_by: i64,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub due_by: Option<i64>,
   /// Whether evidence has been staged for this dispute.
   pub has_evidence: bool,
   /// Whether the last evidence submission was submitted past the due date.
@@ -11,12 +12,14 @@ pub struct EvidenceDetails {
   /// If `true`, then delivery of the latest evidence is _not_ guaranteed.
   pub past_due: bool,
   /// The number of times evidence has been submitted. Typically, you may only submit evidence once.
-  pub submission_count: u16
+  pub submission_count: u16,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Evidence provided to respond to a dispute.
 /// Updating any field in the hash will submit all fields in the hash for review.
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Debug)]
 pub struct Evidence {
   /// Any server or activity logs showing proof that the customer accessed or downloaded the purchased digital product.
   /// This information should include IP addresses, corresponding timestamps, and any detailed recorded activity.
@@ -104,5 +107,7 @@ pub struct Evidence {
   pub uncategorized_file: Option<String>,
   /// Any additional evidence or statements.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub uncategorized_text: Option<String>
+  pub uncategorized_text: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
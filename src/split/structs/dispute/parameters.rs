@@ -0,0 +1,115 @@
+/// The maximum number of characters Stripe accepts in all text fields of `Evidence` combined.
+pub const EVIDENCE_TEXT_LIMIT: usize = 150_000;
+
+/// A field of `Evidence` that expects the ID of a file upload (`crate::file::Purpose::DisputeEvidence`).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EvidenceFile {
+  /// `Evidence::cancellation_policy`
+  CancellationPolicy,
+  /// `Evidence::customer_communication`
+  CustomerCommunication,
+  /// `Evidence::customer_signature`
+  CustomerSignature,
+  /// `Evidence::duplicate_charge_documentation`
+  DuplicateChargeDocumentation,
+  /// `Evidence::receipt`
+  Receipt,
+  /// `Evidence::refund_policy`
+  RefundPolicy,
+  /// `Evidence::service_documentation`
+  ServiceDocumentation,
+  /// `Evidence::shipping_documentation`
+  ShippingDocumentation,
+  /// `Evidence::uncategorized_file`
+  UncategorizedFile
+}
+
+/// The `evidence` and `submit` parameters to update a dispute with `Client::update_dispute_evidence()`.
+/// 
+/// They are validated before sending, so Stripe does not reject the evidence for exceeding `EVIDENCE_TEXT_LIMIT`.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct EvidenceParams {
+  /// The evidence to stage, only fields that are `Some` are sent.
+  pub evidence: Evidence,
+  /// Whether to immediately submit the evidence to the bank.
+  /// If `false`, the evidence is only staged on the dispute.
+  pub submit: bool
+}
+
+impl EvidenceParams {
+  /// Sets the `field` of the evidence to the ID of a file upload.
+  pub fn set_file(&mut self, field: EvidenceFile, file: String) {
+    let value = Some(file);
+    match field {
+      EvidenceFile::CancellationPolicy => self.evidence.cancellation_policy = value,
+      EvidenceFile::CustomerCommunication => self.evidence.customer_communication = value,
+      EvidenceFile::CustomerSignature => self.evidence.customer_signature = value,
+      EvidenceFile::DuplicateChargeDocumentation => self.evidence.duplicate_charge_documentation = value,
+      EvidenceFile::Receipt => self.evidence.receipt = value,
+      EvidenceFile::RefundPolicy => self.evidence.refund_policy = value,
+      EvidenceFile::ServiceDocumentation => self.evidence.service_documentation = value,
+      EvidenceFile::ShippingDocumentation => self.evidence.shipping_documentation = value,
+      EvidenceFile::UncategorizedFile => self.evidence.uncategorized_file = value
+    }
+  }
+
+  /// Returns the number of characters in all text fields (every field except the file uploads) combined.
+  pub fn text_length(&self) -> usize {
+    let e = &self.evidence;
+    [
+      &e.access_activity_log,
+      &e.billing_address,
+      &e.cancellation_policy_disclosure,
+      &e.cancellation_rebuttal,
+      &e.customer_email_address,
+      &e.customer_name,
+      &e.customer_purchase_ip,
+      &e.duplicate_charge_explanation,
+      &e.duplicate_charge_id,
+      &e.product_description,
+      &e.refund_policy_disclosure,
+      &e.refund_refusal_explanation,
+      &e.service_date,
+      &e.shipping_address,
+      &e.shipping_carrier,
+      &e.shipping_date,
+      &e.shipping_tracking_number,
+      &e.uncategorized_text
+    ]
+      .iter()
+      .filter_map(|r| r.as_ref())
+      .map(|r| r.chars().count())
+      .sum()
+  }
+
+  /// Checks that the text fields do not exceed `EVIDENCE_TEXT_LIMIT` characters combined.
+  pub fn validate(&self) -> Result<(), String> {
+    let length = self.text_length();
+    if length > EVIDENCE_TEXT_LIMIT {
+      if log::log_enabled!(log::Level::Error) {
+        log::error!("The text fields of the evidence contain {} characters, but Stripe only accepts {}", length, EVIDENCE_TEXT_LIMIT);
+      }
+      return Err("The text fields of the evidence exceed the character limit".to_string());
+    }
+
+    Ok(())
+  }
+}
+
+impl std::fmt::Display for EvidenceParams {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if let Ok(serde_json::Value::Object(map)) = serde_json::to_value(&self.evidence) {
+      for (k, v) in map.iter() {
+        if let Some(r) = v.as_str() {
+          write!(f, "evidence[{}]={};", k, crate::helper::encode(r))?;
+        }
+      }
+    }
+
+    if self.submit {
+      write!(f, "submit=true;")?;
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,109 @@
+/// The reason given by the cardholder for a dispute.
+/// 
+/// [Dispute reasons](https://stripe.com/docs/disputes/categories)
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Reason {
+  /// The bank could not process the payment.
+  BankCannotProcess,
+  /// The customer paid with a check which was returned.
+  CheckReturned,
+  /// The customer claims a refund or credit was promised but not processed.
+  CreditNotProcessed,
+  /// The customer initiated the dispute with their bank.
+  CustomerInitiated,
+  /// The customer’s bank did not authorize the debit.
+  DebitNotAuthorized,
+  /// The customer claims they were charged multiple times for the same purchase.
+  Duplicate,
+  /// The cardholder claims they didn’t authorize the payment.
+  Fraudulent,
+  /// An uncategorized dispute.
+  General,
+  /// The account details provided were incorrect.
+  IncorrectAccountDetails,
+  /// The customer’s account had insufficient funds.
+  InsufficientFunds,
+  /// The customer claims they did not receive the products or services purchased.
+  ProductNotReceived,
+  /// The product or service was received but was defective, damaged, or not as described.
+  ProductUnacceptable,
+  /// The customer claims that you continued to charge them after a subscription was canceled.
+  SubscriptionCanceled,
+  /// The customer doesn’t recognize the payment appearing on their card statement.
+  Unrecognized,
+  /// A reason that is not (yet) part of this enumeration.
+  #[serde(other)]
+  Unknown
+}
+
+impl Reason {
+  /// Returns the names of the `Evidence` fields Stripe recommends to challenge a dispute with this reason.
+  pub fn recommended_evidence(&self) -> &'static [&'static str] {
+    match self {
+      Self::Fraudulent | Self::Unrecognized => &[
+        "access_activity_log",
+        "billing_address",
+        "customer_communication",
+        "customer_email_address",
+        "customer_name",
+        "customer_purchase_ip",
+        "customer_signature",
+        "product_description",
+        "receipt",
+        "shipping_address",
+        "shipping_documentation",
+        "shipping_tracking_number"
+      ],
+      Self::Duplicate => &[
+        "customer_communication",
+        "duplicate_charge_documentation",
+        "duplicate_charge_explanation",
+        "duplicate_charge_id",
+        "product_description",
+        "receipt"
+      ],
+      Self::SubscriptionCanceled => &[
+        "cancellation_policy",
+        "cancellation_policy_disclosure",
+        "cancellation_rebuttal",
+        "customer_communication",
+        "product_description",
+        "receipt"
+      ],
+      Self::ProductNotReceived => &[
+        "customer_communication",
+        "product_description",
+        "receipt",
+        "service_date",
+        "service_documentation",
+        "shipping_address",
+        "shipping_carrier",
+        "shipping_date",
+        "shipping_documentation",
+        "shipping_tracking_number"
+      ],
+      Self::ProductUnacceptable | Self::CreditNotProcessed => &[
+        "customer_communication",
+        "product_description",
+        "receipt",
+        "refund_policy",
+        "refund_policy_disclosure",
+        "refund_refusal_explanation"
+      ],
+      _ => &[
+        "customer_communication",
+        "product_description",
+        "receipt",
+        "uncategorized_file",
+        "uncategorized_text"
+      ]
+    }
+  }
+}
+
+impl std::fmt::Display for Reason {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+  }
+}
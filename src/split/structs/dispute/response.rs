@@ -1,5 +1,9 @@
 include!("evidence.rs");
 
+include!("reason.rs");
+
+include!("status.rs");
+
 /// Dispute object from 01/17/2023
 /// 
 /// [Dispute object](https://stripe.com/docs/api/disputes/object)
@@ -33,11 +37,52 @@ pub struct Response {
   /// This can be useful for storing additional information about the object in a structured format.
   pub metadata: HashMap<String, String>,
   /// ID of the PaymentIntent that was disputed.
-  pub payment_intent: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_intent: Option<String>,
   /// Reason given by cardholder for dispute.
   /// Possible values are `bank_cannot_process`, `check_returned`, `credit_not_processed`, `customer_initiated`, `debit_not_authorized`, `duplicate`, `fraudulent`, `general`, `incorrect_account_details`, `insufficient_funds`, `product_not_received`, `product_unacceptable`, `subscription_canceled`, or `unrecognized`. Read more about [dispute reasons](https://stripe.com/docs/disputes/categories).
-  pub reason: String,
+  pub reason: Reason,
   /// Current status of dispute.
   /// Possible values are `warning_needs_response`, `warning_under_review`, `warning_closed`, `needs_response`, `under_review`, `charge_refunded`, `won`, or `lost`.
-  pub status: String
-}
\ No newline at end of file
+  pub status: Status,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+impl Response {
+  /// Returns the number of seconds until `evidence_details.due_by` (negative if the deadline has passed).
+  /// 
+  /// Returns `None` if evidence can not be submitted for this dispute.
+  pub fn seconds_until_due(&self) -> Option<i64> {
+    let now = std::time::SystemTime::now()
+      .duration_since(std::time::UNIX_EPOCH)
+      .map(|r| r.as_secs() as i64)
+      .unwrap_or_default();
+
+    self.evidence_details.due_by.map(|r| r - now)
+  }
+
+  /// Returns `true` if evidence still has to be submitted and the deadline has not passed yet.
+  pub fn is_actionable(&self) -> bool {
+    self.status.needs_response() && self.seconds_until_due().map(|r| r > 0).unwrap_or(false)
+  }
+
+  /// Returns the names of the `Evidence` fields Stripe recommends for the `reason` of this dispute.
+  pub fn recommended_evidence(&self) -> &'static [&'static str] {
+    self.reason.recommended_evidence()
+  }
+
+  /// Returns the names of all recommended `Evidence` fields that have not been provided yet.
+  pub fn missing_evidence(&self) -> Vec<&'static str> {
+    let provided = match serde_json::to_value(&self.evidence) {
+      Ok(serde_json::Value::Object(r)) => r,
+      _ => serde_json::Map::new()
+    };
+
+    self.recommended_evidence()
+      .iter()
+      .filter(|r| !provided.get(**r).and_then(|r| r.as_str()).map(|r| !r.is_empty()).unwrap_or(false))
+      .copied()
+      .collect()
+  }
+}
@@ -0,0 +1,39 @@
+/// The status of a dispute.
+/// 
+/// [Dispute statuses](https://stripe.com/docs/disputes/how-disputes-work#dispute-statuses)
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+  /// An inquiry was opened and evidence can be submitted.
+  WarningNeedsResponse,
+  /// Evidence for an inquiry was submitted and is being reviewed.
+  WarningUnderReview,
+  /// The inquiry was closed without becoming a dispute.
+  WarningClosed,
+  /// Evidence must be submitted to challenge the dispute.
+  NeedsResponse,
+  /// Evidence was submitted and is being reviewed by the bank.
+  UnderReview,
+  /// The charge was refunded and the dispute was closed.
+  ChargeRefunded,
+  /// The dispute was decided in your favor.
+  Won,
+  /// The dispute was decided in the cardholder’s favor.
+  Lost,
+  /// A status that is not (yet) part of this enumeration.
+  #[serde(other)]
+  Unknown
+}
+
+impl Status {
+  /// Returns `true` if evidence still has to be submitted (`needs_response` or `warning_needs_response`).
+  pub fn needs_response(&self) -> bool {
+    matches!(self, Self::NeedsResponse | Self::WarningNeedsResponse)
+  }
+}
+
+impl std::fmt::Display for Status {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+  }
+}
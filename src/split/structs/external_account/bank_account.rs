@@ -0,0 +1,52 @@
+/// Bank Account object from 10/19/2026
+/// 
+/// [Bank Account object](https://stripe.com/docs/api/external_account_bank_accounts/object)
+/// 
+/// The `object` field (`bank_account`) is part of `ExternalAccount`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BankAccount {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// The ID of the account that the bank account is associated with.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub account: Option<String>,
+  /// The name of the person or business that owns the bank account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub account_holder_name: Option<String>,
+  /// The type of entity that holds the account, either `individual` or `company`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub account_holder_type: Option<String>,
+  /// The bank account type, e.g. `checking` or `savings` (only used in some countries).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub account_type: Option<String>,
+  /// A set of available payout methods for this bank account, `standard` and/or `instant`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub available_payout_methods: Option<Vec<String>>,
+  /// Name of the bank associated with the routing number (e.g., `WELLS FARGO`).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub bank_name: Option<String>,
+  /// Two-letter ISO code representing the country the bank account is located in.
+  pub country: String,
+  /// Three-letter [ISO code for the currency](https://stripe.com/docs/payouts) paid out to the bank account.
+  pub currency: String,
+  /// Whether this bank account is the default external account for its currency.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub default_for_currency: Option<bool>,
+  /// Uniquely identifies this particular bank account.
+  /// You can use this attribute to check whether two bank accounts are the same.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fingerprint: Option<String>,
+  /// The last four digits of the bank account number.
+  pub last4: String,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metadata: Option<HashMap<String, String>>,
+  /// The routing transit number for the bank account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub routing_number: Option<String>,
+  /// One of `new`, `validated`, `verified`, `verification_failed`, or `errored`.
+  pub status: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,51 @@
+/// Card object from 10/19/2026
+/// 
+/// [Card object](https://stripe.com/docs/api/external_account_cards/object)
+/// 
+/// The `object` field (`card`) is part of `ExternalAccount`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Card {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// The ID of the account that the card is associated with.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub account: Option<String>,
+  /// A set of available payout methods for this card, `standard` and/or `instant`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub available_payout_methods: Option<Vec<String>>,
+  /// Card brand, e.g. `Visa` or `MasterCard`.
+  pub brand: String,
+  /// Two-letter ISO code representing the country of the card.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub country: Option<String>,
+  /// Three-letter [ISO code for currency](https://stripe.com/docs/payouts) paid out to the card.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub currency: Option<String>,
+  /// Whether this card is the default external account for its currency.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub default_for_currency: Option<bool>,
+  /// Two-digit number representing the card’s expiration month.
+  pub exp_month: u8,
+  /// Four-digit number representing the card’s expiration year.
+  pub exp_year: u16,
+  /// Uniquely identifies this particular card number.
+  /// You can use this attribute to check whether two customers who’ve signed up with you are using the same card number.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fingerprint: Option<String>,
+  /// Card funding type, one of `credit`, `debit`, `prepaid`, or `unknown`.
+  pub funding: String,
+  /// The last four digits of the card.
+  pub last4: String,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metadata: Option<HashMap<String, String>>,
+  /// Cardholder name.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// For external accounts that are cards, possible values are `new` and `errored`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub status: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,31 @@
+/// A bank account or a debit card that payouts of an account are sent to.
+/// 
+/// [External Accounts](https://stripe.com/docs/api/external_accounts)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "object")]
+pub enum ExternalAccount {
+  /// A bank account.
+  #[serde(rename = "bank_account")]
+  BankAccount(BankAccount),
+  /// A debit card.
+  #[serde(rename = "card")]
+  Card(Card)
+}
+
+impl ExternalAccount {
+  /// Returns the unique identifier of the bank account or card.
+  pub fn id(&self) -> &str {
+    match self {
+      Self::BankAccount(r) => &r.id,
+      Self::Card(r) => &r.id
+    }
+  }
+
+  /// Returns `true` if this is the default external account for its currency.
+  pub fn is_default_for_currency(&self) -> bool {
+    match self {
+      Self::BankAccount(r) => r.default_for_currency,
+      Self::Card(r) => r.default_for_currency
+    }.unwrap_or(false)
+  }
+}
@@ -0,0 +1,44 @@
+/// The purpose of an uploaded file.
+/// 
+/// Use it with `format!` to create the body of a request, e.g. `format!("purpose={};", Purpose::DisputeEvidence)`.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Purpose {
+  /// Additional documentation requirements that can be requested for an account.
+  AccountRequirement,
+  /// Additional verification for custom accounts.
+  AdditionalVerification,
+  /// A business icon.
+  BusinessIcon,
+  /// A business logo.
+  BusinessLogo,
+  /// Customer signature image.
+  CustomerSignature,
+  /// Evidence to submit with a dispute response.
+  DisputeEvidence,
+  /// User-accessible copies of query results from the Reporting dataset.
+  FinanceReportRun,
+  /// A document to verify the identity of an account owner during account provisioning.
+  IdentityDocument,
+  /// Image of a document collected by Stripe Identity.
+  IdentityDocumentDownloadable,
+  /// A self-assessment PCI questionnaire.
+  PciDocument,
+  /// Image of a selfie collected by Stripe Identity.
+  Selfie,
+  /// Sigma scheduled query file for export and download.
+  SigmaScheduledQuery,
+  /// A user-uploaded tax document.
+  TaxDocumentUserUpload,
+  /// A splashscreen to be displayed on a Terminal reader.
+  TerminalReaderSplashscreen,
+  /// A purpose that is not (yet) part of this enumeration.
+  #[serde(other)]
+  Unknown
+}
+
+impl std::fmt::Display for Purpose {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+  }
+}
@@ -0,0 +1,59 @@
+/// File object from 10/19/2026
+/// 
+/// [File object](https://stripe.com/docs/api/files/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // file
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// The time at which the file expires and is no longer available in epoch seconds.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expires_at: Option<i64>,
+  /// A filename for the file, suitable for saving to a filesystem.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub filename: Option<String>,
+  /// A list of [file links](https://stripe.com/docs/api#file_links) that point at this file.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub links: Option<crate::file_link::ResponseList>,
+  /// The [purpose](https://stripe.com/docs/file-upload#uploading-a-file) of the uploaded file.
+  pub purpose: Purpose,
+  /// The size in bytes of the file object.
+  pub size: i64,
+  /// A user friendly title for the document.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// The type of the file returned (e.g., `csv`, `pdf`, `jpg`, or `png`).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub r#type: Option<String>,
+  /// The URL from which the file can be downloaded using your live secret API key.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub url: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+impl Response {
+  /// Returns an `Info` to download the contents of this file.
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(r) = client.retrieve_file("FILE_ID".to_string()).get().await {
+  ///     let stripe_response = r.retrieve_contents(&client).download().await;
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_contents<'a>(&self, client: &'a crate::Client) -> Info<'a> {
+    client.retrieve_file_contents(self.id.clone())
+  }
+}
@@ -0,0 +1,105 @@
+/// The content of a file to upload.
+pub enum Content {
+  /// The complete file in memory.
+  Bytes(Vec<u8>),
+  /// A file that is streamed to Stripe while it is read, see `Content::from_reader()`.
+  Stream(reqwest::Body)
+}
+
+impl Content {
+  /// Streams the file from `reader` (e.g. a `tokio::fs::File`) instead of loading it into memory.
+  pub fn from_reader<R>(reader: R) -> Self where R: tokio::io::AsyncRead + Send + Sync + 'static {
+    Self::Stream(reqwest::Body::wrap_stream(tokio_util::io::ReaderStream::new(reader)))
+  }
+}
+
+impl From<Vec<u8>> for Content {
+  fn from(value: Vec<u8>) -> Self {
+    Self::Bytes(value)
+  }
+}
+
+impl From<&[u8]> for Content {
+  fn from(value: &[u8]) -> Self {
+    Self::Bytes(value.to_vec())
+  }
+}
+
+/// A file upload as multipart form data to `files.stripe.com`.
+/// 
+/// Unlike `Info`, it is consumed by `send()` because the content can only be read once.
+pub struct Upload<'a> {
+  pub(crate) purpose: Purpose,
+  pub(crate) file_name: String,
+  pub(crate) content: Content,
+  pub(crate) body: Option<String>,
+  pub(crate) secret_key: String,
+  pub(crate) reqwest_client: &'a reqwest::Client,
+  pub(crate) lenient: bool
+}
+
+impl Upload<'_> {
+  fn create_send_request(self) -> (reqwest::RequestBuilder, bool) {
+    let mut form = reqwest::multipart::Form::new()
+      .text("purpose", self.purpose.to_string());
+
+    // The remaining parameters (e.g. `file_link_data[create]=true;`) become text fields
+    for (k, v) in self.body.as_deref().unwrap_or_default().split(';').filter_map(|r| r.split_once('=')) {
+      form = form.text(k.to_string(), decode(v));
+    }
+
+    let part = match self.content {
+      Content::Bytes(r) => reqwest::multipart::Part::bytes(r),
+      Content::Stream(r) => reqwest::multipart::Part::stream(r)
+    };
+    form = form.part("file", part.file_name(self.file_name));
+
+    let request = self.reqwest_client
+      .post(FILE_UPLOAD_URL)
+      .basic_auth(self.secret_key, None::<&str>)
+      .multipart(form);
+
+    (request, self.lenient)
+  }
+
+  /// Sends a "POST" request to Stripe's API.
+  pub async fn send(self) -> Result<Response, (String, Option<crate::error::Info>)> {
+    self.send_with_meta().await.map(|r| r.body)
+  }
+
+  /// Sends a "POST" request to Stripe's API and keeps the `crate::meta::Meta` of the response.
+  pub async fn send_with_meta(self) -> Result<crate::meta::Response<Response>, (String, Option<crate::error::Info>)> {
+    let (request, lenient) = self.create_send_request();
+    crate::helper::make_reqwest_with_meta::<Response>(request, lenient).await
+  }
+
+  /// Sends a "POST" request to Stripe's API and returns the undecoded JSON of the response.
+  pub async fn send_raw(self) -> Result<crate::meta::Response<serde_json::Value>, (String, Option<crate::error::Info>)> {
+    let (request, _) = self.create_send_request();
+    crate::helper::make_reqwest_with_meta::<serde_json::Value>(request, false).await
+  }
+}
+
+/// Reverse the percent-encoding of a value in the body of a request.
+fn decode(input: &str) -> String {
+  let bytes = input.as_bytes();
+  let mut result = Vec::with_capacity(bytes.len());
+  let mut i = 0;
+  while i < bytes.len() {
+    let hex = bytes.get(i + 1..i + 3)
+      .and_then(|r| std::str::from_utf8(r).ok())
+      .and_then(|r| u8::from_str_radix(r, 16).ok());
+    match (bytes[i], hex) {
+      (b'%', Some(r)) => {
+        result.push(r);
+        i += 3;
+      },
+      (r, _) => {
+        result.push(r);
+        i += 1;
+      }
+    }
+  }
+
+  String::from_utf8_lossy(&result).into_owned()
+}
@@ -0,0 +1,31 @@
+/// File Link object from 10/19/2026
+/// 
+/// [File Link object](https://stripe.com/docs/api/file_links/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // file_link
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// Whether this link is already expired.
+  pub expired: bool,
+  /// Time at which the link expires.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expires_at: Option<i64>,
+  /// The file object this link points to.
+  pub file: String,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  pub metadata: HashMap<String, String>,
+  /// The publicly accessible URL to download the file.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub url: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,70 @@
+/// Invoice line item object from 10/19/2026
+/// 
+/// [Invoice line item object](https://stripe.com/docs/api/invoices/line_item)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LineItem {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // line_item
+  /// The amount, in cents.
+  pub amount: i64,
+  /// The integer amount in cents representing the amount for this line item, excluding all tax and discounts.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub amount_excluding_tax: Option<i64>,
+  /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+  /// Must be a [supported currency](https://stripe.com/docs/currencies).
+  pub currency: String,
+  /// An arbitrary string attached to the object.
+  /// Often useful for displaying to users.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// If `true`, discounts will apply to this line item.
+  /// Always `false` for prorations.
+  pub discountable: bool,
+  /// The ID of the [invoice item](https://stripe.com/docs/api/invoiceitems) associated with this line item if any.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub invoice_item: Option<String>,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// Note that for line items with `type=subscription` this will reflect the metadata of the subscription that caused the line item to be created.
+  pub metadata: HashMap<String, String>,
+  /// The period this line item covers.
+  pub period: Period,
+  /// The price of the line item.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub price: Option<crate::price::Response>,
+  /// Whether this is a proration.
+  pub proration: bool,
+  /// The quantity of the subscription, if the line item is a subscription or a proration.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub quantity: Option<u64>,
+  /// The subscription that the invoice item pertains to, if any.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub subscription: Option<String>,
+  /// The subscription item that generated this line item.
+  /// Left empty if the line item is not an explicit result of a subscription.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub subscription_item: Option<String>,
+  /// A string identifying the type of the source of this line item, either an `invoiceitem` or a `subscription`.
+  #[serde(rename = "type")]
+  pub r#type: String,
+  /// The amount in cents representing the unit amount for this line item, excluding all tax and discounts.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unit_amount_excluding_tax: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The period a `LineItem` covers.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Period {
+  /// The end of the period, which must be greater than or equal to the start.
+  pub end: i64,
+  /// The start of the period.
+  pub start: i64,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
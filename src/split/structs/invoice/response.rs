@@ -0,0 +1,188 @@
+include!("line_item.rs");
+
+include!("status.rs");
+
+/// Invoice object from 10/19/2026
+/// 
+/// [Invoice object](https://stripe.com/docs/api/invoices/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  /// `None` for an upcoming invoice, which is only a preview.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub id: Option<String>,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // invoice
+  /// The country of the business associated with this invoice, most often the business creating the invoice.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub account_country: Option<String>,
+  /// The public name of the business associated with this invoice, most often the business creating the invoice.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub account_name: Option<String>,
+  /// Final amount due at this time for this invoice.
+  /// If the invoice’s total is smaller than the minimum charge amount, for example, or if there is account credit that can be applied to the invoice, the `amount_due` may be 0.
+  pub amount_due: i64,
+  /// The amount, in cents, that was paid.
+  pub amount_paid: i64,
+  /// The difference between `amount_due` and `amount_paid`, in cents.
+  pub amount_remaining: i64,
+  /// ID of the Connect Application that created the invoice.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub application: Option<String>,
+  /// The fee in cents that will be applied to the invoice and transferred to the application owner’s Stripe account when the invoice is paid.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub application_fee_amount: Option<i64>,
+  /// Number of payment attempts made for this invoice, from the perspective of the payment retry schedule.
+  pub attempt_count: u64,
+  /// Whether an attempt has been made to pay the invoice.
+  pub attempted: bool,
+  /// Controls whether Stripe performs [automatic collection](https://stripe.com/docs/invoicing/integration/automatic-advancement-collection) of the invoice.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub auto_advance: Option<bool>,
+  /// Indicates the reason why the invoice was created, e.g. `subscription_cycle`, `subscription_update` or `manual`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub billing_reason: Option<String>,
+  /// ID of the latest charge generated for this invoice, if any.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub charge: Option<String>,
+  /// Either `charge_automatically`, or `send_invoice`.
+  pub collection_method: String,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+  /// Must be a [supported currency](https://stripe.com/docs/currencies).
+  pub currency: String,
+  /// The ID of the customer who will be billed.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer: Option<String>,
+  /// The customer’s email.
+  /// Until the invoice is finalized, this field will equal `customer.email`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer_email: Option<String>,
+  /// The customer’s name.
+  /// Until the invoice is finalized, this field will equal `customer.name`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer_name: Option<String>,
+  /// ID of the default payment method for the invoice.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub default_payment_method: Option<String>,
+  /// An arbitrary string attached to the object.
+  /// Often useful for displaying to users.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// The date on which payment for this invoice is due.
+  /// This value will be `None` for invoices where `collection_method=charge_automatically`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub due_date: Option<i64>,
+  /// Ending customer balance after the invoice is finalized.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub ending_balance: Option<i64>,
+  /// Footer displayed on the invoice.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub footer: Option<String>,
+  /// The URL for the hosted invoice page, which allows customers to view and pay an invoice.
+  /// `None` if the invoice has not been finalized yet.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub hosted_invoice_url: Option<String>,
+  /// The link to download the PDF for the invoice.
+  /// `None` if the invoice has not been finalized yet.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub invoice_pdf: Option<String>,
+  /// The individual line items that make up the invoice.
+  /// Use `get_all_line_items()` to receive more than the first page.
+  pub lines: ResponseLineItems,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metadata: Option<HashMap<String, String>>,
+  /// The time at which payment will next be attempted.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub next_payment_attempt: Option<i64>,
+  /// A unique, identifying string that appears on emails sent to the customer for this invoice.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub number: Option<String>,
+  /// The account (if any) for which the funds of the invoice payment are intended.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub on_behalf_of: Option<String>,
+  /// Whether payment was successfully collected for this invoice.
+  pub paid: bool,
+  /// Returns `true` if the invoice was manually marked paid, returns `false` if the invoice hasn’t been paid yet or was paid on Stripe.
+  pub paid_out_of_band: bool,
+  /// The PaymentIntent associated with this invoice.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_intent: Option<String>,
+  /// End of the usage period during which invoice items were added to this invoice.
+  pub period_end: i64,
+  /// Start of the usage period during which invoice items were added to this invoice.
+  pub period_start: i64,
+  /// Total amount of all post-payment credit notes issued for this invoice.
+  pub post_payment_credit_notes_amount: i64,
+  /// Total amount of all pre-payment credit notes issued for this invoice.
+  pub pre_payment_credit_notes_amount: i64,
+  /// This is the transaction number that appears on email receipts sent for this invoice.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub receipt_number: Option<String>,
+  /// Starting customer balance before the invoice is finalized.
+  pub starting_balance: i64,
+  /// Extra information about an invoice for the customer’s credit card statement.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub statement_descriptor: Option<String>,
+  /// The status of the invoice, see `Status`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub status: Option<Status>,
+  /// The timestamps at which the invoice status was updated.
+  pub status_transitions: StatusTransitions,
+  /// The subscription that this invoice was prepared for, if any.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub subscription: Option<String>,
+  /// Only set for upcoming invoices that preview prorations.
+  /// The time used to calculate prorations.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub subscription_proration_date: Option<i64>,
+  /// Total of all subscriptions, invoice items, and prorations on the invoice before any invoice level discount or exclusive tax is applied.
+  pub subtotal: i64,
+  /// The integer amount in cents representing the subtotal of the invoice before any invoice level discount or tax is applied.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub subtotal_excluding_tax: Option<i64>,
+  /// The amount of tax on this invoice.
+  /// This is the sum of all the tax amounts on this invoice.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tax: Option<i64>,
+  /// ID of the test clock this invoice belongs to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub test_clock: Option<String>,
+  /// Total after discounts and taxes.
+  pub total: i64,
+  /// The integer amount in cents representing the total amount of the invoice including all discounts but excluding all tax.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub total_excluding_tax: Option<i64>,
+  /// Invoices are automatically paid or sent 1 hour after webhooks are delivered, or until all webhook delivery attempts have been exhausted.
+  /// This field tracks the time when webhooks for this invoice were successfully delivered.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub webhooks_delivered_at: Option<i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The timestamps at which an invoice status was updated.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StatusTransitions {
+  /// The time that the invoice draft was finalized.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub finalized_at: Option<i64>,
+  /// The time that the invoice was marked uncollectible.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub marked_uncollectible_at: Option<i64>,
+  /// The time that the invoice was paid.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub paid_at: Option<i64>,
+  /// The time that the invoice was voided.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub voided_at: Option<i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
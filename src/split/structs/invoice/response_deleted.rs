@@ -0,0 +1,15 @@
+/// Deleted invoice object from 10/19/2026
+/// 
+/// [Delete a draft invoice](https://stripe.com/docs/api/invoices/delete)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ResponseDeleted {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // invoice
+  /// Always `true` for a deleted object.
+  pub deleted: bool,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,16 @@
+/// When retrieving an invoice, you’ll get a lines property containing the total count of line items and the first handful of those items.
+/// Use `Info::get_all_line_items()` to receive all of them.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ResponseLineItems {
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String,
+  /// The end of the requested URL of the API.
+  pub url: String,
+  /// If more than the data received now exists `true` otherwise `false`.
+  pub has_more: bool,
+  /// All received data.
+  pub data: Vec<LineItem>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
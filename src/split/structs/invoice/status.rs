@@ -0,0 +1,26 @@
+/// The status of an invoice.
+/// 
+/// [Invoice statuses](https://stripe.com/docs/invoicing/overview#workflow-overview)
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+  /// The invoice isn’t ready to use yet, all invoices start in this state.
+  Draft,
+  /// The invoice is finalized and awaiting payment.
+  Open,
+  /// The invoice is paid.
+  Paid,
+  /// The customer is unlikely to pay the invoice.
+  Uncollectible,
+  /// The invoice is canceled.
+  Void,
+  /// A status that is not (yet) part of this enumeration.
+  #[serde(other)]
+  Unknown
+}
+
+impl std::fmt::Display for Status {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+  }
+}
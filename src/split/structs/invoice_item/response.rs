@@ -0,0 +1,76 @@
+/// Invoice item object from 10/19/2026
+/// 
+/// [Invoice item object](https://stripe.com/docs/api/invoiceitems/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // invoiceitem
+  /// Amount (in the `currency` specified) of the invoice item.
+  /// This should always be equal to `unit_amount * quantity`.
+  pub amount: i64,
+  /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+  /// Must be a [supported currency](https://stripe.com/docs/currencies).
+  pub currency: String,
+  /// The ID of the customer who will be billed when this invoice item is billed.
+  pub customer: String,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub date: i64,
+  /// An arbitrary string attached to the object.
+  /// Often useful for displaying to users.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// If `true`, discounts will apply to this invoice item.
+  /// Always `false` for prorations.
+  pub discountable: bool,
+  /// The ID of the invoice this invoice item belongs to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub invoice: Option<String>,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metadata: Option<HashMap<String, String>>,
+  /// The period associated with this invoice item.
+  pub period: Period,
+  /// The price of the invoice item.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub price: Option<crate::price::Response>,
+  /// Whether the invoice item was created automatically as a proration adjustment when the customer switched plans.
+  pub proration: bool,
+  /// Quantity of units for the invoice item.
+  /// If the invoice item is a proration, the quantity of the subscription that the proration was computed for.
+  pub quantity: u64,
+  /// The subscription that this invoice item has been created for, if any.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub subscription: Option<String>,
+  /// The subscription item that this invoice item has been created for, if any.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub subscription_item: Option<String>,
+  /// ID of the test clock this invoice item belongs to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub test_clock: Option<String>,
+  /// Unit amount (in the `currency` specified) of the invoice item.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unit_amount: Option<i64>,
+  /// Same as `unit_amount`, but contains a decimal value with at most 12 decimal places.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unit_amount_decimal: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The period associated with an invoice item.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Period {
+  /// The end of the period, which must be greater than or equal to the start.
+  pub end: i64,
+  /// The start of the period.
+  pub start: i64,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,15 @@
+/// Deleted invoice item object from 10/19/2026
+/// 
+/// [Delete an invoice item](https://stripe.com/docs/api/invoiceitems/delete)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ResponseDeleted {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // invoiceitem
+  /// Always `true` for a deleted object.
+  pub deleted: bool,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
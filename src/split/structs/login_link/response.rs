@@ -0,0 +1,16 @@
+/// Login Link object from 10/19/2026
+/// 
+/// [Login Link object](https://stripe.com/docs/api/account/login_link)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // login_link
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// The URL for the login link.
+  pub url: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
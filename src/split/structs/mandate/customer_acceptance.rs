@@ -1,6 +1,9 @@
 /// If this is a Mandate accepted offline, this hash contains details about the offline acceptance.
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct CustomerAcceptanceOffline;
+pub struct CustomerAcceptanceOffline {
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
 
 /// If this is a Mandate accepted online, this hash contains details about the online acceptance.
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -8,7 +11,9 @@ pub struct CustomerAcceptanceOnline {
   /// The IP address from which the Mandate was accepted by the customer.
   pub ip_address: String,
   ///The user agent of the browser from which the Mandate was accepted by the customer.
-  pub user_agent: String
+  pub user_agent: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Details about the customer’s acceptance of the mandate.
@@ -24,5 +29,7 @@ pub struct CustomerAcceptance {
   pub online: Option<CustomerAcceptanceOnline>,
   /// The type of customer acceptance information included with the Mandate.
   /// One of `online` or `offline`.
-  pub r#type: String
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
@@ -9,7 +9,9 @@ pub struct PaymentMethodDetailsAcssDebit {
   /// Payment schedule for the mandate.
   pub payment_schedule: String,
   /// Transaction type of the mandate.
-  pub transaction_type: String
+  pub transaction_type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If this mandate is associated with a `au_becs_debit` payment method, this hash contains mandate information specific to the `au_becs_debit` payment method.
@@ -17,7 +19,9 @@ pub struct PaymentMethodDetailsAcssDebit {
 pub struct PaymentMethodDetailsAuBecsDebit {
   /// The URL of the mandate.
   /// This URL generally contains sensitive information about the customer and should be shared with them exclusively.
-  pub url: String
+  pub url: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If this mandate is associated with a `bacs_debit` payment method, this hash contains mandate information specific to the `bacs_debit` payment method.
@@ -29,7 +33,9 @@ pub struct PaymentMethodDetailsBacsDebit {
   /// The unique reference identifying the mandate on the Bacs network.
   pub reference: String,
   /// The URL that will contain the mandate that the customer has signed.
-  pub url: String
+  pub url: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Details for off-session mandates.
@@ -42,7 +48,9 @@ pub struct PaymentMethodDetailsBlikOffSession {
   /// Frequency interval of each recurring payment.
   pub interval: String,
   /// Frequency indicator of each recurring payment.
-  pub interval_count: u16
+  pub interval_count: u16,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If this mandate is associated with a `blik` payment method, this hash contains mandate information specific to the `blik` payment method.
@@ -54,15 +62,23 @@ pub struct PaymentMethodDetailsBlik {
   pub off_session: PaymentMethodDetailsBlikOffSession,
   /// Type of the mandate.
   pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If this mandate is associated with a `card` payment method, this hash contains mandate information specific to the `card` payment method.
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct PaymentMethodDetailsCard;
+pub struct PaymentMethodDetailsCard {
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
 
 /// If this mandate is associated with a `link` payment method, this hash contains mandate information specific to the `link` payment method.
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct PaymentMethodDetailsLink;
+pub struct PaymentMethodDetailsLink {
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
 
 /// If this mandate is associated with a `sepa_debit` payment method, this hash contains mandate information specific to the `sepa_debit` payment method.
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -71,12 +87,17 @@ pub struct PaymentMethodDetailsSepaDebit {
   pub reference: String,
   /// The URL of the mandate.
   /// This URL generally contains sensitive information about the customer and should be shared with them exclusively.
-  pub url: String
+  pub url: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If this mandate is associated with a `us_bank_account` payment method, this hash contains mandate information specific to the `us_bank_account` payment method.
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct PaymentMethodDetailsUsBankAccount;
+pub struct PaymentMethodDetailsUsBankAccount {
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
 
 /// Additional mandate information specific to the payment method type.
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -108,5 +129,7 @@ pub struct PaymentMethodDetails {
   pub r#type: String,
   /// If this mandate is associated with a `us_bank_account` payment method, this hash contains mandate information specific to the `us_bank_account` payment method.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub us_bank_account: Option<PaymentMethodDetailsUsBankAccount>
+  pub us_bank_account: Option<PaymentMethodDetailsUsBankAccount>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
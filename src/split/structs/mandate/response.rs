@@ -2,7 +2,10 @@ include!("customer_acceptance.rs");
 
 /// If this is a `multi_use` mandate, this hash contains details about the mandate.
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct MultiUse;
+pub struct MultiUse {
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
 
 include!("payment_method_details.rs");
 
@@ -12,7 +15,9 @@ pub struct SingleUse {
   /// On a single use mandate, the amount of the payment.
   pub amount: u32,
   /// On a single use mandate, the currency of the payment.
-  pub currency: String
+  pub currency: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Mandate object from 01/12/2023
@@ -42,5 +47,7 @@ pub struct Response {
   pub r#type: String,
   /// If this is a `single_use` mandate, this hash contains details about the mandate.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub single_use: Option<String> // Possible
+  pub single_use: Option<SingleUse>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
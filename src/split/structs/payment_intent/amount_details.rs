@@ -3,7 +3,9 @@
 pub struct AmountDetailsTip {
   /// Portion of the amount that corresponds to a tip.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub amount: Option<u32>
+  pub amount: Option<u32>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Details about items included in the amount
@@ -11,5 +13,7 @@ pub struct AmountDetailsTip {
 pub struct AmountDetails {
   /// Portion of the amount that corresponds to a tip.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub tip: Option<AmountDetailsTip>
+  pub tip: Option<AmountDetailsTip>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
@@ -2,5 +2,7 @@
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct AutomaticPaymentMethods {
   /// Automatically calculates compatible payment methods
-  pub enabled: bool
+  pub enabled: bool,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
@@ -12,21 +12,26 @@ pub struct LastPaymentError {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub decline_code: Option<String>,
   /// A URL to more information about the [error code](https://stripe.com/docs/error-codes) reported.
-  pub doc_url: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub doc_url: Option<String>,
   /// A human-readable message providing more details about the error.
   /// For card errors, these messages can be shown to your users.
-  pub message: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message: Option<String>,
   /// If the error is parameter-specific, the parameter related to the error.
   /// For example, you can use this to display a message near the correct form field.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub param: Option<String>,
-  /// ID of the payment method used in this PaymentIntent.
-  pub payment_method: String,
+  /// The PaymentMethod object for errors returned on a request involving a PaymentMethod.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_method: Option<serde_json::Value>,
   /// If the error is specific to the type of payment method, the payment method type that had a problem.
   /// This field is only populated for invoice-related errors.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub payment_method_type: Option<String>,
   /// The type of error returned.
   /// One of `api_error`, `card_error`, `idempotency_error`, or `invalid_request_error`
-  pub r#type: String
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
@@ -8,7 +8,9 @@ pub struct NextActionAlipayHandleRedirect {
   /// If the customer does not exit their browser while authenticating, they will be redirected to this specified URL after completion.
   pub return_url: String,
   /// The URL you must redirect your customer to in order to authenticate the payment.
-  pub url: String
+  pub url: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Contains Boleto details necessary for the customer to complete the payment.
@@ -21,7 +23,9 @@ pub struct NextActionBoletoDisplayDetails {
   /// The boleto number.
   pub number: String,
   /// The URL to the downloadable boleto voucher PDF.
-  pub pdf: String
+  pub pdf: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Contains instructions for processing off session recurring payments with Indian issued cards.
@@ -33,7 +37,9 @@ pub struct NextActionCardAwaitNotification {
   pub charge_attempt_at: Option<i64>,
   /// For payments greater than INR 15000, the customer must provide explicit approval of the payment with their bank.
   /// For payments of lower amount, no customer action is required.
-  pub customer_approval_required: bool
+  pub customer_approval_required: bool,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// An IBAN-based FinancialAddress
@@ -46,7 +52,9 @@ pub struct NextActionDisplayBankTransferInstructionsFinancialAddressesIban {
   /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
   pub country: String,
   /// The IBAN of the account.
-  pub iban: String
+  pub iban: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// An account number and sort code-based FinancialAddress
@@ -57,7 +65,9 @@ pub struct NextActionDisplayBankTransferInstructionsFinancialAddressesSortCode {
   /// The account number
   pub account_number: String,
   /// The six-digit sort code
-  pub sort_code: String
+  pub sort_code: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// A SPEI-based FinancialAddress
@@ -68,7 +78,9 @@ pub struct NextActionDisplayBankTransferInstructionsFinancialAddressesSpei {
   ///The short banking institution name
   pub bank_name: String,
   /// The CLABE number
-  pub clabe: String
+  pub clabe: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// A Zengin-based FinancialAddress
@@ -87,7 +99,9 @@ pub struct NextActionDisplayBankTransferInstructionsFinancialAddressesZengin {
   /// The branch code of the account
   pub branch_code: String,
   /// The branch name of the account
-  pub branch_name: String
+  pub branch_name: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -107,7 +121,9 @@ pub struct NextActionDisplayBankTransferInstructionsFinancialAddresses {
   pub r#type: String,
   /// A Zengin-based FinancialAddress
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub zengin: Option<NextActionDisplayBankTransferInstructionsFinancialAddressesZengin>
+  pub zengin: Option<NextActionDisplayBankTransferInstructionsFinancialAddressesZengin>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Contains the bank transfer details necessary for the customer to complete the payment.
@@ -125,12 +141,17 @@ pub struct NextActionDisplayBankTransferInstructions {
   /// Instruct your customer to include this code in the reference or memo field of their bank transfer.
   pub reference: String,
   /// Type of bank transfer
-  pub r#type: String
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Contains Konbini details necessary for the customer to complete the payment.
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct NextActionKonbiniDisplayDetails;
+pub struct NextActionKonbiniDisplayDetails {
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
 
 /// Contains OXXO details necessary for the customer to complete the payment.
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -140,7 +161,9 @@ pub struct NextActionOxxoDisplayDetails {
   /// The URL for the hosted OXXO voucher page, which allows customers to view and print an OXXO voucher.
   pub hosted_voucher_url: String,
   /// OXXO reference number.
-  pub number: String
+  pub number: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// The field that contains PayNow QR code info
@@ -153,7 +176,9 @@ pub struct NextActionPaynowDisplayQrCode {
   /// The image_url_png string used to render QR code
   pub image_url_png: String,
   /// The image_url_svg string used to render QR code
-  pub image_url_svg: String
+  pub image_url_svg: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// The field that contains Pix QR code info
@@ -168,7 +193,9 @@ pub struct NextActionPixDisplayQrCode {
   /// The image_url_png string used to render png QR code
   pub image_url_png: String,
   /// The image_url_svg string used to render svg QR code
-  pub image_url_svg: String
+  pub image_url_svg: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// The field that contains PromptPay QR code info
@@ -181,7 +208,9 @@ pub struct NextActionPromptpayDisplayQrCode {
   /// The PNG path used to render the QR code, can be used as the source in an HTML img tag
   pub image_url_png: String,
   /// The SVG path used to render the QR code, can be used as the source in an HTML img tag
-  pub image_url_svg: String
+  pub image_url_svg: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Contains instructions for authenticating a payment by redirecting your customer to another page or application.
@@ -190,13 +219,18 @@ pub struct NextActionRedirectToUrl {
   /// If the customer does not exit their browser while authenticating, they will be redirected to this specified URL after completion.
   pub return_url: String,
   /// The URL you must redirect your customer to in order to authenticate the payment.
-  pub url: String
+  pub url: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// When confirming a PaymentIntent with Stripe.js, Stripe.js depends on the contents of this dictionary to invoke authentication flows.
 /// The shape of the contents is subject to change and is only intended to be used by Stripe.js.
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct NextActionUseStripeSdk;
+pub struct NextActionUseStripeSdk {
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
 
 /// Contains details describing microdeposits verification flow.
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -207,7 +241,9 @@ pub struct NextActionVerifyWithMicrodeposits {
   pub hosted_verification_url: String,
   /// The type of the microdeposit sent to the customer.
   /// Used to distinguish between different verification methods.
-  pub microdeposit_type: String
+  pub microdeposit_type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// The field that contains WeChat Pay QR code info
@@ -222,7 +258,9 @@ pub struct NextActionWechatPayDisplayQrCode {
   /// The image_url_png string used to render QR code
   pub image_url_png: String,
   /// The image_url_svg string used to render QR code
-  pub image_url_svg: String
+  pub image_url_svg: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Info required for android app to app redirect
@@ -241,14 +279,18 @@ pub struct NextActionWechatPayRedirectToAndrodApp {
   /// A signature
   pub sign: String,
   /// Specifies the current time in epoch format
-  pub timestamp: String
+  pub timestamp: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Info required for iOS app to app redirect
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct NextActionWechatPayRedirectToIosApp {
   /// An universal link that redirect to WeChat Pay app
-  pub native_url: String
+  pub native_url: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If present, this property tells you what actions you need to take in order for your customer to fulfill a payment using the provided source.
@@ -301,5 +343,7 @@ pub struct NextAction {
   pub wechat_pay_redirect_to_android_app: Option<NextActionWechatPayRedirectToAndrodApp>,
   /// Info required for iOS app to app redirect
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub wechat_pay_redirect_to_ios_app: Option<NextActionWechatPayRedirectToIosApp>
+  pub wechat_pay_redirect_to_ios_app: Option<NextActionWechatPayRedirectToIosApp>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
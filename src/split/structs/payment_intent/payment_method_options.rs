@@ -11,7 +11,9 @@ pub struct PaymentMethodOptionsAcssDebitMandateOptions {
   /// Payment schedule for the mandate.
   pub payment_schedule: String,
   /// Transaction type of the mandate.
-  pub transaction_type: String
+  pub transaction_type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `acss_debit`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -29,7 +31,9 @@ pub struct PaymentMethodOptionsAcssDebit {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub setup_future_usage: Option<String>,
   /// Bank account verification method.
-  pub verification_method: String
+  pub verification_method: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `affirm`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -45,7 +49,9 @@ pub struct PaymentMethodOptionsAffirm {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `afterpay_clearpay`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -66,7 +72,9 @@ pub struct PaymentMethodOptionsAfterpayClearpay {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `alipay`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -79,7 +87,9 @@ pub struct PaymentMethodOptionsAlipay {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `au_becs_debit`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -92,7 +102,9 @@ pub struct PaymentMethodOptionsAuBecsDebit {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `bacs_debit`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -105,7 +117,9 @@ pub struct PaymentMethodOptionsBacsDebit {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `bancontact`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -121,12 +135,17 @@ pub struct PaymentMethodOptionsBancontact {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `blik`, this hash contains the configurations that will be applied to each payment attempt of that type.
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct PaymentMethodOptionsBlik;
+pub struct PaymentMethodOptionsBlik {
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
 
 /// If the PaymentIntent’s payment_method_types includes `boleto`, this hash contains the configurations that will be applied to each payment attempt of that type.
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -139,7 +158,9 @@ pub struct PaymentMethodOptionsBoleto {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Installment plan selected for this PaymentIntent.
@@ -151,7 +172,9 @@ pub struct PaymentMethodOptionsCardInstallmentsPlan {
   /// One of `month`.
   pub interval: String,
   /// Type of installment plan, one of `fixed_count`.
-  pub r#type: String
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Installment details for this payment (Mexico only).
@@ -164,7 +187,9 @@ pub struct PaymentMethodOptionsCardInstallments {
   /// Whether Installments are enabled for this PaymentIntent.
   pub enabled: bool,
   /// Installment plan selected for this PaymentIntent.
-  pub plan: PaymentMethodOptionsCardInstallmentsPlan
+  pub plan: PaymentMethodOptionsCardInstallmentsPlan,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Configuration options for setting up an eMandate for cards issued in India.
@@ -200,7 +225,9 @@ pub struct PaymentMethodOptionsCardMandateOptions {
   pub start_date: i64,
   /// Specifies the type of mandates supported.
   /// Possible values are `india`.
-  pub supported_types: Vec<String>
+  pub supported_types: Vec<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the SetupIntent’s payment_method_types includes `card`, this hash contains the configurations that will be applied to each setup attempt of that type.
@@ -248,6 +275,8 @@ pub struct PaymentMethodOptionsCard {
   /// On card statements, the concatenation of both prefix and suffix (including separators) will appear truncated to 17 characters.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub statement_descriptor_suffix_kanji: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `card_present`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -260,7 +289,9 @@ pub struct PaymentMethodOptionsCardPresent {
   pub request_extended_authorization: bool,
   /// Request ability to [increment](https://stripe.com/docs/terminal/features/incremental-authorizations) this PaymentIntent if the combination of MCC and card brand is eligible.
   /// Check [incremental_authorization_supported](https://stripe.com/docs/api/charges/object#charge_object-payment_method_details-card_present-incremental_authorization_supported) in the [Confirm](https://stripe.com/docs/api/payment_intents/confirm) response to verify support.
-  pub request_incremental_authorization_support: bool
+  pub request_incremental_authorization_support: bool,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Configuration for eu_bank_transfer
@@ -268,7 +299,9 @@ pub struct PaymentMethodOptionsCardPresent {
 pub struct PaymentMethodOptionsCustomerBalanceBankTransferEuBankTransfer {
   /// The desired country code of the bank account information.
   /// Permitted values include: `DE`, `ES`, `FR`, `IE`, or `NL`.
-  pub country: String
+  pub country: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Configuration for the bank transfer funding type, if the `funding_type` is set to `bank_transfer`.
@@ -276,7 +309,9 @@ pub struct PaymentMethodOptionsCustomerBalanceBankTransferEuBankTransfer {
 pub struct PaymentMethodOptionsCustomerBalanceBankTransfer {
   /// Configuration for eu_bank_transfer
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub eu_bank_transfer: Option<PaymentMethodOptionsCustomerBalanceBankTransferEuBankTransfer>
+  pub eu_bank_transfer: Option<PaymentMethodOptionsCustomerBalanceBankTransferEuBankTransfer>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `customer_balance`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -296,7 +331,9 @@ pub struct PaymentMethodOptionsCustomerBalance {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `eps`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -309,7 +346,9 @@ pub struct PaymentMethodOptionsEps {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `fpx`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -322,7 +361,9 @@ pub struct PaymentMethodOptionsFpx {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `giropay`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -335,7 +376,9 @@ pub struct PaymentMethodOptionsGiropay {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `grabpay`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -348,7 +391,9 @@ pub struct PaymentMethodOptionsGrabpay {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `ideal`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -361,12 +406,17 @@ pub struct PaymentMethodOptionsIdeal {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `interac_present`, this hash contains the configurations that will be applied to each payment attempt of that type.
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct PaymentMethodOptionsInteracPresent;
+pub struct PaymentMethodOptionsInteracPresent {
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
 
 /// If the PaymentIntent’s payment_method_types includes `klarna`, this hash contains the configurations that will be applied to each payment attempt of that type.
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -374,6 +424,8 @@ pub struct PaymentMethodOptionsKlarna {
   /// Controls when the funds will be captured from the customer’s account.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub capture_method: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `konbini`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -400,7 +452,9 @@ pub struct PaymentMethodOptionsKonbini {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the SetupIntent’s payment_method_types includes `link`, this hash contains the configurations that will be applied to each setup attempt of that type.
@@ -419,7 +473,9 @@ pub struct PaymentMethodOptionsLink {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `oxxo`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -435,7 +491,9 @@ pub struct PaymentMethodOptionsOxxo {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `p24`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -448,7 +506,9 @@ pub struct PaymentMethodOptionsP24 {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `paynow`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -461,7 +521,9 @@ pub struct PaymentMethodOptionsPaynow {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `pix`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -478,7 +540,9 @@ pub struct PaymentMethodOptionsPix {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `promptpay`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -491,12 +555,17 @@ pub struct PaymentMethodOptionsPromptpay {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Additional fields for Mandate creation
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct PaymentMethodOptionsSepaDebitMandateOptions;
+pub struct PaymentMethodOptionsSepaDebitMandateOptions {
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
 
 /// If the PaymentIntent’s `payment_method_types` includes sepa_debit, this hash contains the configurations that will be applied to each payment attempt of that type.
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -511,7 +580,9 @@ pub struct PaymentMethodOptionsSepaDebit {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `sofort`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -527,14 +598,18 @@ pub struct PaymentMethodOptionsSofort {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Additional fields for Financial Connections Session creation
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PaymentMethodOptionsUsBankAccountFinancialConnections {
   /// The list of permissions to request. The payment_method permission must be included.
-  pub permissions: Vec<String>
+  pub permissions: Vec<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `us_bank_account`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -549,7 +624,9 @@ pub struct PaymentMethodOptionsUsBankAccount {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `wechat_pay`, this hash contains the configurations that will be applied to each payment attempt of that type.
@@ -568,7 +645,9 @@ pub struct PaymentMethodOptionsWechatPay {
   /// 
   /// When processing card payments, Stripe also uses `setup_future_usage` to dynamically optimize your payment flow and comply with regional legislation and network rules, such as [SCA](https://stripe.com/docs/strong-customer-authentication).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub setup_future_usage: Option<String>
+  pub setup_future_usage: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Payment-method-specific configuration for this SetupIntent.
@@ -663,5 +742,7 @@ pub struct PaymentMethodOptions {
   pub us_bank_account: Option<PaymentMethodOptionsUsBankAccount>,
   /// If the PaymentIntent’s payment_method_types includes `wechat_pay`, this hash contains the configurations that will be applied to each payment attempt of that type.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub wechat_pay: Option<PaymentMethodOptionsWechatPay>
+  pub wechat_pay: Option<PaymentMethodOptionsWechatPay>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
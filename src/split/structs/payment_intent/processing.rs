@@ -6,7 +6,9 @@ pub struct ProcessingCardCustomerNotification {
   pub approval_requested: bool,
   /// If customer approval is required, they need to provide approval before this time.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub completes_at: Option<i64>
+  pub completes_at: Option<i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If the PaymentIntent’s payment_method_types includes `card`, this hash contains the details on the `processing` state of the payment.
@@ -14,7 +16,9 @@ pub struct ProcessingCardCustomerNotification {
 pub struct ProcessingCard {
   /// For recurring payments of Indian cards, this hash contains details on whether customer approval is required, and until when the payment will be in `processing` state
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub customer_notification: Option<ProcessingCardCustomerNotification>
+  pub customer_notification: Option<ProcessingCardCustomerNotification>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// If present, this property tells you about the processing state of the payment.
@@ -24,5 +28,7 @@ pub struct Processing {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub card: Option<ProcessingCard>,
   /// Type of the payment method for which payment is in `processing` state, one of `card`.
-  pub r#type: String
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
@@ -63,7 +63,8 @@ pub struct Response {
   /// Make sure that you have TLS enabled on any page that includes the client secret.
   /// 
   /// Refer to our docs to accept a payment and learn about how client_secret should be handled.
-  pub client_secret: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub client_secret: Option<String>,
   /// MISSING DOCUMENTATION
   pub confirmation_method: String,
   /// Time at which the object was created.
@@ -153,5 +154,77 @@ pub struct Response {
   /// A string that identifies the resulting payment as part of a group.
   /// See the PaymentIntents [use case for connected accounts](https://stripe.com/docs/payments/connected-accounts) for details.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub transfer_group: Option<String>
-}
\ No newline at end of file
+  pub transfer_group: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+impl Response {
+  /// Returns an `Info` to retrieve the `crate::invoice::Response` that created this PaymentIntent.
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(r) = client.retrieve_payment_intent("PAYMENT_INTENT_ID".to_string()).get().await {
+  ///     if let Some(invoice) = r.retrieve_invoice(&client) {
+  ///       let stripe_response = invoice.get().await;
+  ///     }
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  #[cfg(feature = "invoice")]
+  pub fn retrieve_invoice<'a>(&self, client: &'a crate::Client) -> Option<crate::invoice::Info<'a>> {
+    self.invoice
+      .as_ref()
+      .map(|r| client.retrieve_invoice(r.clone()))
+  }
+
+  /// Returns an `Info` to list all `crate::transfer::Response` that share the `transfer_group` of this PaymentIntent.
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(r) = client.retrieve_payment_intent("PAYMENT_INTENT_ID".to_string()).get().await {
+  ///     if let Some(info) = r.list_transfers(&client) {
+  ///       let stripe_response = info.get_list().await;
+  ///     }
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  #[cfg(feature = "transfer")]
+  pub fn list_transfers<'a>(&self, client: &'a crate::Client) -> Option<crate::transfer::Info<'a>> {
+    self.transfer_group
+      .as_ref()
+      .map(|r| client.list_transfer(format!("transfer_group={};", crate::helper::encode(r))))
+  }
+
+  /// Returns an `Info` to list all `crate::refund::Response` of this PaymentIntent.
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(r) = client.retrieve_payment_intent("PAYMENT_INTENT_ID".to_string()).get().await {
+  ///     let stripe_response = r.list_refunds(&client).get_list().await;
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  #[cfg(feature = "refund")]
+  pub fn list_refunds<'a>(&self, client: &'a crate::Client) -> crate::refund::Info<'a> {
+    client.list_refund(format!("payment_intent={};", crate::helper::encode(&self.id)))
+  }
+}
@@ -18,7 +18,9 @@ pub struct ShippingAddress {
   pub postal_code: Option<String>,
   /// State, county, province, or region.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub state: Option<String>
+  pub state: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
 
 /// Shipping information for this PaymentIntent.
@@ -37,5 +39,7 @@ pub struct Shipping {
   /// The tracking number for a physical product, obtained from the delivery service.
   /// If multiple tracking numbers were generated for this purchase, please separate them with commas.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub tracking_number: Option<String>
+  pub tracking_number: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
@@ -9,5 +9,7 @@ pub struct TransferData {
   pub amount: u32,
   /// The account (if any) the payment will be attributed to for tax reporting, and where funds from the payment will be transferred to upon payment success.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub destination: Option<String>
+  pub destination: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
@@ -0,0 +1,65 @@
+/// The `after_completion` parameter of a payment link.
+/// 
+/// Use it with `format!` to create the body of a request, e.g. `format!("{}", AfterCompletionParams::Redirect("https://example.com".to_string()))`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AfterCompletionParams {
+  /// Show a hosted confirmation page with an optional custom message.
+  HostedConfirmation(Option<String>),
+  /// Redirect the customer to the given URL.
+  Redirect(String)
+}
+
+impl std::fmt::Display for AfterCompletionParams {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::HostedConfirmation(None) => write!(f, "after_completion[type]=hosted_confirmation;"),
+      Self::HostedConfirmation(Some(r)) => write!(f, "after_completion[type]=hosted_confirmation;after_completion[hosted_confirmation][custom_message]={};", crate::helper::encode(r)),
+      Self::Redirect(r) => write!(f, "after_completion[type]=redirect;after_completion[redirect][url]={};", crate::helper::encode(r))
+    }
+  }
+}
+
+/// The `restrictions` parameter of a payment link.
+/// 
+/// Use it with `format!` to create the body of a request, e.g. `format!("{}", RestrictionsParams { completed_sessions_limit: 10 })`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RestrictionsParams {
+  /// The maximum number of checkout sessions that can be completed before the payment link is deactivated.
+  pub completed_sessions_limit: u64
+}
+
+impl std::fmt::Display for RestrictionsParams {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "restrictions[completed_sessions][limit]={};", self.completed_sessions_limit)
+  }
+}
+
+/// One entry of the `line_items` parameter of a payment link.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LineItemParams {
+  /// The ID of the price object.
+  pub price: String,
+  /// The quantity of the line item being purchased.
+  pub quantity: u64,
+  /// Allows the customer to adjust the quantity between `minimum` and `maximum` (inclusive).
+  pub adjustable_quantity: Option<(u64, u64)>
+}
+
+/// The `line_items` parameter of a payment link.
+/// 
+/// Use it with `format!` to create the body of a request, e.g. `format!("{}", LineItemsParams(vec![...]))`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LineItemsParams(pub Vec<LineItemParams>);
+
+impl std::fmt::Display for LineItemsParams {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for (i, r) in self.0.iter().enumerate() {
+      write!(f, "line_items[{}][price]={};line_items[{}][quantity]={};", i, crate::helper::encode(&r.price), i, r.quantity)?;
+      if let Some((minimum, maximum)) = r.adjustable_quantity {
+        write!(f, "line_items[{}][adjustable_quantity][enabled]=true;line_items[{}][adjustable_quantity][minimum]={};line_items[{}][adjustable_quantity][maximum]={};", i, i, minimum, i, maximum)?;
+      }
+    }
+
+    Ok(())
+  }
+}
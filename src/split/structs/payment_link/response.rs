@@ -0,0 +1,115 @@
+/// Payment Link object from 10/19/2026
+/// 
+/// [Payment Link object](https://stripe.com/docs/api/payment_links/payment_links/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // payment_link
+  /// Whether the payment link’s `url` is active.
+  /// If `false`, customers visiting the URL will be shown a page saying that the link has been deactivated.
+  pub active: bool,
+  /// Behavior after the purchase is complete.
+  pub after_completion: AfterCompletion,
+  /// Whether user redeemable promotion codes are enabled.
+  pub allow_promotion_codes: bool,
+  /// The amount of the application fee (if any) that will be requested to be applied to the payment and transferred to the application owner’s Stripe account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub application_fee_amount: Option<i64>,
+  /// This represents the percentage of the subscription invoice subtotal that will be transferred to the application owner’s Stripe account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub application_fee_percent: Option<f64>,
+  /// Configuration for collecting the customer’s billing address, either `auto` or `required`.
+  pub billing_address_collection: String,
+  /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+  /// Must be a [supported currency](https://stripe.com/docs/currencies).
+  pub currency: String,
+  /// Configuration for Customer creation during checkout, either `always` or `if_required`.
+  pub customer_creation: String,
+  /// The line items representing what is being sold.
+  /// Only included if `expand[]=line_items` was part of the request, otherwise use `list_payment_link_line_items()`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub line_items: Option<crate::checkout::ResponseLineItems>,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  pub metadata: HashMap<String, String>,
+  /// The account on behalf of which to charge.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub on_behalf_of: Option<String>,
+  /// Configuration for collecting a payment method during checkout, either `always` or `if_required`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_method_collection: Option<String>,
+  /// The list of payment method types that customers can use.
+  /// When `None`, Stripe will dynamically show relevant payment methods you’ve enabled in your [payment method settings](https://dashboard.stripe.com/settings/payment_methods).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_method_types: Option<Vec<String>>,
+  /// Settings that restrict the usage of a payment link.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub restrictions: Option<Restrictions>,
+  /// Indicates the type of transaction being performed which customizes relevant text on the page, such as the submit button.
+  /// One of `auto`, `book`, `donate`, or `pay`.
+  pub submit_type: String,
+  /// The public URL that can be shared with customers.
+  pub url: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Behavior after the purchase of a payment link is complete.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AfterCompletion {
+  /// Configuration when `type=hosted_confirmation`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub hosted_confirmation: Option<HostedConfirmation>,
+  /// Configuration when `type=redirect`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub redirect: Option<Redirect>,
+  /// The specified behavior after the purchase is complete, either `hosted_confirmation` or `redirect`.
+  #[serde(rename = "type")]
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Configuration of a hosted confirmation page.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HostedConfirmation {
+  /// The custom message that is displayed to the customer after the purchase is complete.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_message: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Configuration of a redirect after the purchase is complete.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Redirect {
+  /// The URL the customer will be redirected to after the purchase is complete.
+  pub url: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Settings that restrict the usage of a payment link.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Restrictions {
+  /// Configuration for the `completed_sessions` restriction type.
+  pub completed_sessions: CompletedSessions,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The `completed_sessions` restriction of a payment link.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CompletedSessions {
+  /// The current number of checkout sessions that have been completed on the payment link which count towards the `completed_sessions` restriction to be met.
+  pub count: u64,
+  /// The maximum number of checkout sessions that can be completed for the `completed_sessions` restriction to be met.
+  pub limit: u64,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,108 @@
+/// The method used to send a payout.
+/// 
+/// Use it with `format!` to create the body of a request, e.g. `format!("method={};", Method::Instant)`.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Method {
+  /// The payout arrives in the usual time frame.
+  Standard,
+  /// The payout arrives within 30 minutes, only supported for debit cards and some bank accounts.
+  Instant,
+  /// A method that is not (yet) part of this enumeration.
+  #[serde(other)]
+  Unknown
+}
+
+impl std::fmt::Display for Method {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+  }
+}
+
+/// The balance type a payout is sent from.
+/// 
+/// Use it with `format!` to create the body of a request, e.g. `format!("source_type={};", SourceType::Card)`.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceType {
+  /// Funds from ACH payments.
+  BankAccount,
+  /// Funds from card payments.
+  Card,
+  /// Funds from FPX payments.
+  Fpx,
+  /// A source type that is not (yet) part of this enumeration.
+  #[serde(other)]
+  Unknown
+}
+
+impl std::fmt::Display for SourceType {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+  }
+}
+
+/// The parameters to create a payout with `Client::create_payout()`.
+/// 
+/// `amount` and `currency` are required.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct PayoutParams {
+  /// A positive integer in cents representing how much to payout.
+  pub amount: u32,
+  /// Three-letter ISO currency code, in lowercase.
+  pub currency: String,
+  /// The method used to send this payout, defaults to `standard`.
+  pub method: Option<Method>,
+  /// The balance type of your Stripe balance to draw this payout from.
+  pub source_type: Option<SourceType>,
+  /// The ID of a bank account or a card to send the payout to.
+  /// If no destination is supplied, the default external account for the specified currency will be used.
+  pub destination: Option<String>,
+  /// An arbitrary string attached to the object.
+  pub description: Option<String>,
+  /// A string to be displayed on the recipient’s bank or card statement.
+  pub statement_descriptor: Option<String>
+}
+
+impl PayoutParams {
+  /// Checks that `amount` is positive and `currency` is set.
+  pub fn validate(&self) -> Result<(), String> {
+    if self.amount == 0 {
+      if log::log_enabled!(log::Level::Error) {
+        log::error!("A payout requires an `amount` greater than 0");
+      }
+      return Err("The `amount` must be greater than 0".to_string());
+    }
+    if self.currency.is_empty() {
+      if log::log_enabled!(log::Level::Error) {
+        log::error!("A payout requires a `currency`");
+      }
+      return Err("The `currency` is required".to_string());
+    }
+
+    Ok(())
+  }
+}
+
+impl std::fmt::Display for PayoutParams {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "amount={};currency={};", self.amount, crate::helper::encode(&self.currency))?;
+    if let Some(r) = self.method {
+      write!(f, "method={};", r)?;
+    }
+    if let Some(r) = self.source_type {
+      write!(f, "source_type={};", r)?;
+    }
+    if let Some(r) = &self.destination {
+      write!(f, "destination={};", crate::helper::encode(r))?;
+    }
+    if let Some(r) = &self.description {
+      write!(f, "description={};", crate::helper::encode(r))?;
+    }
+    if let Some(r) = &self.statement_descriptor {
+      write!(f, "statement_descriptor={};", crate::helper::encode(r))?;
+    }
+
+    Ok(())
+  }
+}
@@ -1,4 +1,25 @@
-/// Payout object from 01/12/2023
+/// The reconciliation status of a payout.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconciliationStatus {
+  /// The payout is not an automatic payout and cannot be reconciled.
+  NotApplicable,
+  /// The balance transactions of the payout are still being collected.
+  InProgress,
+  /// All balance transactions of the payout are available.
+  Completed,
+  /// A status that is not (yet) part of this enumeration.
+  #[serde(other)]
+  Unknown
+}
+
+impl std::fmt::Display for ReconciliationStatus {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+  }
+}
+
+/// Payout object from 10/19/2026
 /// 
 /// [Payout object](https://stripe.com/docs/api/payouts/object)
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -10,13 +31,22 @@ pub struct Response {
   pub object: String, // payout
   /// Amount (in cents) to be transferred to your bank account or debit card.
   pub amount: u32,
+  /// The application fee (if any) for the payout.
+  /// [See the Connect documentation](https://stripe.com/docs/connect/instant-payouts#monetization-and-fees) for details.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub application_fee: Option<String>,
+  /// The amount of the application fee (if any) requested for the payout.
+  /// [See the Connect documentation](https://stripe.com/docs/connect/instant-payouts#monetization-and-fees) for details.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub application_fee_amount: Option<u32>,
   /// Date the payout is expected to arrive in the bank.
   /// This factors in delays like weekends or bank holidays.
   pub arrival_date: i64,
   /// Returns `true` if the payout was created by an [automated payout schedule](https://stripe.com/docs/payouts#payout-schedule), and `false` if it was [requested manually](https://stripe.com/docs/payouts#manual-payouts).
   pub automatic: bool,
   /// ID of the balance transaction that describes the impact of this payout on your account balance.
-  pub balance_transaction: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub balance_transaction: Option<String>,
   /// Time at which the object was created.
   /// Measured in seconds since the Unix epoch.
   pub created: i64,
@@ -25,9 +55,11 @@ pub struct Response {
   pub currency: String,
   /// An arbitrary string attached to the object.
   /// Often useful for displaying to users.
-  pub description: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
   /// ID of the bank account or card the payout was sent to.
-  pub destination: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub destination: Option<String>,
   /// If the payout failed or was canceled, this will be the ID of the balance transaction that reversed the initial balance transaction, and puts the funds from the failed payout back in your balance.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub failure_balance_transaction: Option<String>,
@@ -35,6 +67,9 @@ pub struct Response {
   /// See [Types of payout failures](https://stripe.com/docs/api#payout_failures) for a list of failure codes.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub failure_code: Option<String>,
+  /// Message to user further explaining reason for payout failure if available.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub failure_message: Option<String>,
   /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
   pub livemode: bool,
   /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
@@ -46,6 +81,8 @@ pub struct Response {
   /// If the payout reverses another, this is the ID of the original payout.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub original_payout: Option<String>,
+  /// If `completed`, the [Balance Transactions API](https://stripe.com/docs/api/balance_transactions/list#balance_transaction_list-payout) may be used to list all Balance Transactions that were paid out in this payout.
+  pub reconciliation_status: ReconciliationStatus,
   /// If the payout was reversed, this is the ID of the payout that reverses this payout.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub reversed_by: Option<String>,
@@ -61,5 +98,37 @@ pub struct Response {
   /// Some failed payouts may initially show as `paid` but then change to `failed`.
   pub status: String,
   /// Can be `bank_account` or `card`.
-  pub r#type: String
-}
\ No newline at end of file
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+impl Response {
+  /// Returns an `Info` to retrieve the `crate::external_account::ExternalAccount` this payout was sent to.
+  /// 
+  /// # Arguments
+  /// 
+  /// * `account` - The unique ID of the account that created the payout, use the ID of your own account for the platform
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(r) = client.retrieve_payout("PAYOUT_ID".to_string()).get().await {
+  ///     if let Some(info) = r.retrieve_destination(&client, "ACCOUNT_ID".to_string()) {
+  ///       let stripe_response = info.get().await;
+  ///     }
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  #[cfg(feature = "account")]
+  pub fn retrieve_destination<'a>(&self, client: &'a crate::Client, account: String) -> Option<crate::external_account::Info<'a>> {
+    self.destination
+      .as_ref()
+      .map(|r| client.retrieve_external_account(account, r.clone()))
+  }
+}
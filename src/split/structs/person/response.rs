@@ -0,0 +1,87 @@
+/// Person object from 10/19/2026
+/// 
+/// [Person object](https://stripe.com/docs/api/persons/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // person
+  /// The account the person is associated with.
+  pub account: String,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// The person’s date of birth.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub dob: Option<Dob>,
+  /// The person’s email address.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub email: Option<String>,
+  /// The person’s first name.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub first_name: Option<String>,
+  /// Information about the upcoming new requirements for this person, including what information needs to be collected, and by when.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub future_requirements: Option<crate::account::Requirements>,
+  /// The person’s last name.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub last_name: Option<String>,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metadata: Option<HashMap<String, String>>,
+  /// The person’s phone number.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub phone: Option<String>,
+  /// Describes the person’s relationship to the account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub relationship: Option<Relationship>,
+  /// Information about the requirements for this person, including what information needs to be collected, and by when.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub requirements: Option<crate::account::Requirements>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The date of birth of a person.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Dob {
+  /// The day of birth, between 1 and 31.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub day: Option<u8>,
+  /// The month of birth, between 1 and 12.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub month: Option<u8>,
+  /// The four-digit year of birth.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub year: Option<u16>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The relationship of a person to an account.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Relationship {
+  /// Whether the person is a director of the account’s legal entity.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub director: Option<bool>,
+  /// Whether the person has significant responsibility to control, manage, or direct the organization.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub executive: Option<bool>,
+  /// Whether the person is an owner of the account’s legal entity.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub owner: Option<bool>,
+  /// The percent owned by the person of the account’s legal entity.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub percent_ownership: Option<f64>,
+  /// Whether the person is authorized as the primary representative of the account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub representative: Option<bool>,
+  /// The person’s title (e.g., CEO, Support Engineer).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,35 @@
+/// The recurring components of a price.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Recurring {
+  /// Specifies a usage aggregation strategy for prices of `usage_type=metered`.
+  /// One of `sum`, `last_during_period`, `last_ever` or `max`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub aggregate_usage: Option<String>,
+  /// The frequency at which a subscription is billed.
+  /// One of `day`, `week`, `month` or `year`.
+  pub interval: String,
+  /// The number of intervals (specified in the `interval` attribute) between subscription billings.
+  /// For example, `interval=month` and `interval_count=3` bills every 3 months.
+  pub interval_count: u64,
+  /// Default number of trial days when subscribing a customer to this price using [`trial_from_plan=true`](https://stripe.com/docs/api#create_subscription-trial_from_plan).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub trial_period_days: Option<u64>,
+  /// Configures how the quantity per period should be determined.
+  /// Can be either `metered` or `licensed`.
+  /// `licensed` automatically bills the `quantity` set when adding it to a subscription.
+  /// `metered` aggregates the total usage based on usage records.
+  pub usage_type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Apply a transformation to the reported usage or set quantity before computing the amount billed.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TransformQuantity {
+  /// Divide usage by this number.
+  pub divide_by: u64,
+  /// After division, either round the result `up` or `down`.
+  pub round: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
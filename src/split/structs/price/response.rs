@@ -0,0 +1,123 @@
+include!("recurring.rs");
+
+include!("tier.rs");
+
+/// Price object from 10/19/2026
+/// 
+/// [Price object](https://stripe.com/docs/api/prices/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // price
+  /// Whether the price can be used for new purchases.
+  pub active: bool,
+  /// Describes how to compute the price per period.
+  /// Either `per_unit` or `tiered`.
+  /// 
+  /// `per_unit` indicates that the fixed amount (specified in `unit_amount` or `unit_amount_decimal`) will be charged per unit in `quantity` (for prices with `usage_type=licensed`), or per unit of total usage (for prices with `usage_type=metered`).
+  /// `tiered` indicates that the unit pricing will be computed using a tiering strategy as defined using the `tiers` and `tiers_mode` attributes.
+  pub billing_scheme: String,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+  /// Must be a [supported currency](https://stripe.com/docs/currencies).
+  pub currency: String,
+  /// Prices defined in each available currency option.
+  /// Only included if `expand[]=currency_options` was part of the request.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub currency_options: Option<HashMap<String, CurrencyOption>>,
+  /// When set, provides configuration for the amount to be adjusted by the customer during Checkout Sessions and Payment Links.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_unit_amount: Option<CustomUnitAmount>,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// A lookup key used to retrieve prices dynamically from a static string.
+  /// This may be up to 200 characters.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub lookup_key: Option<String>,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  pub metadata: HashMap<String, String>,
+  /// A brief description of the price, hidden from customers.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub nickname: Option<String>,
+  /// The ID of the product this price is associated with.
+  pub product: String,
+  /// The recurring components of a price such as `interval` and `usage_type`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub recurring: Option<Recurring>,
+  /// Only required if a [default tax behavior](https://stripe.com/docs/tax/products-prices-tax-categories-tax-behavior#setting-a-default-tax-behavior-(recommended)) was not provided in the Stripe Tax settings.
+  /// One of `inclusive`, `exclusive`, or `unspecified`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tax_behavior: Option<String>,
+  /// Each element represents a pricing tier.
+  /// This parameter requires `billing_scheme` to be set to `tiered`.
+  /// Only included if `expand[]=tiers` was part of the request.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tiers: Option<Vec<Tier>>,
+  /// Defines if the tiering price should be `graduated` or `volume` based.
+  /// In `volume`-based tiering, the maximum quantity within a period determines the per unit price.
+  /// In `graduated` tiering, pricing can change as the quantity grows.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tiers_mode: Option<String>,
+  /// Apply a transformation to the reported usage or set quantity before computing the amount billed.
+  /// Cannot be combined with `tiers`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub transform_quantity: Option<TransformQuantity>,
+  /// One of `one_time` or `recurring` depending on whether the price is for a one-time purchase or a recurring (subscription) purchase.
+  #[serde(rename = "type")]
+  pub r#type: String,
+  /// The unit amount in cents to be charged, represented as a whole integer if possible.
+  /// Only set if `billing_scheme=per_unit`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unit_amount: Option<i64>,
+  /// The unit amount in cents to be charged, represented as a decimal string with at most 12 decimal places.
+  /// Only set if `billing_scheme=per_unit`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unit_amount_decimal: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The price of a `Response` in one currency option.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CurrencyOption {
+  /// When set, provides configuration for the amount to be adjusted by the customer during Checkout Sessions and Payment Links.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub custom_unit_amount: Option<CustomUnitAmount>,
+  /// One of `inclusive`, `exclusive`, or `unspecified`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tax_behavior: Option<String>,
+  /// Each element represents a pricing tier.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tiers: Option<Vec<Tier>>,
+  /// The unit amount in cents to be charged, represented as a whole integer if possible.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unit_amount: Option<i64>,
+  /// The unit amount in cents to be charged, represented as a decimal string with at most 12 decimal places.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unit_amount_decimal: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Configuration for the amount to be adjusted by the customer.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CustomUnitAmount {
+  /// The maximum unit amount the customer can specify for this item.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub maximum: Option<i64>,
+  /// The minimum unit amount the customer can specify for this item.
+  /// Must be at least the minimum charge amount.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub minimum: Option<i64>,
+  /// The starting unit amount which can be updated by the customer.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub preset: Option<i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
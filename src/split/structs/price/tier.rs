@@ -0,0 +1,22 @@
+/// A pricing tier of a price with `billing_scheme=tiered`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Tier {
+  /// Price for the entire tier.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub flat_amount: Option<i64>,
+  /// Same as `flat_amount`, but contains a decimal value with at most 12 decimal places.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub flat_amount_decimal: Option<String>,
+  /// Per unit price for units relevant to the tier.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unit_amount: Option<i64>,
+  /// Same as `unit_amount`, but contains a decimal value with at most 12 decimal places.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unit_amount_decimal: Option<String>,
+  /// Up to and including to this quantity will be contained in the tier.
+  /// `None` for the last tier, which contains all remaining units.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub up_to: Option<u64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
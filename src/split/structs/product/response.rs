@@ -0,0 +1,87 @@
+/// Product object from 10/19/2026
+/// 
+/// [Product object](https://stripe.com/docs/api/products/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // product
+  /// Whether the product is currently available for purchase.
+  pub active: bool,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// The ID of the [Price](https://stripe.com/docs/api/prices) object that is the default price for this product.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub default_price: Option<String>,
+  /// The product’s description, meant to be displayable to the customer.
+  /// Use this field to optionally store a long form explanation of the product being sold for your own rendering purposes.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// A list of up to 8 URLs of images for this product, meant to be displayable to the customer.
+  pub images: Vec<String>,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// A list of up to 15 marketing features for this product.
+  /// These are displayed in [pricing tables](https://stripe.com/docs/payments/checkout/pricing-table).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub marketing_features: Option<Vec<MarketingFeature>>,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  pub metadata: HashMap<String, String>,
+  /// The product’s name, meant to be displayable to the customer.
+  pub name: String,
+  /// The dimensions of this product for shipping purposes.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub package_dimensions: Option<PackageDimensions>,
+  /// Whether this product is shipped (i.e., physical goods).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub shippable: Option<bool>,
+  /// Extra information about a product which will appear on your customer’s credit card statement.
+  /// In the case that multiple products are billed at once, the first statement descriptor will be used.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub statement_descriptor: Option<String>,
+  /// A [tax code](https://stripe.com/docs/tax/tax-categories) ID.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tax_code: Option<String>,
+  /// A label that represents units of this product.
+  /// When set, this will be included in customers’ receipts, invoices, Checkout, and the customer portal.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unit_label: Option<String>,
+  /// Time at which the object was last updated.
+  /// Measured in seconds since the Unix epoch.
+  pub updated: i64,
+  /// A URL of a publicly-accessible webpage for this product.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub url: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// A marketing feature of a product.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MarketingFeature {
+  /// The marketing feature name.
+  /// Up to 80 characters long.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The dimensions of a product for shipping purposes.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PackageDimensions {
+  /// Height, in inches.
+  pub height: f64,
+  /// Length, in inches.
+  pub length: f64,
+  /// Weight, in ounces.
+  pub weight: f64,
+  /// Width, in inches.
+  pub width: f64,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
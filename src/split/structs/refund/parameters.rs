@@ -0,0 +1,63 @@
+/// The parameters to create a refund with `Client::create_refund()`.
+/// 
+/// Either `charge` or `payment_intent` is required.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct RefundParams {
+  /// The identifier of the charge to refund.
+  pub charge: Option<String>,
+  /// The identifier of the PaymentIntent to refund.
+  pub payment_intent: Option<String>,
+  /// A positive integer in cents representing how much of this charge to refund.
+  /// Defaults to the entire charge amount.
+  pub amount: Option<u32>,
+  /// The reason of the refund.
+  pub reason: Option<Reason>,
+  /// Whether the application fee should be refunded when refunding this charge.
+  pub refund_application_fee: Option<bool>,
+  /// Whether the transfer should be reversed when refunding this charge.
+  pub reverse_transfer: Option<bool>,
+  /// For payment methods without native refund support (e.g. Konbini, PromptPay), the email of the customer to send refund instructions to.
+  pub instructions_email: Option<String>
+}
+
+impl RefundParams {
+  /// Checks that either `charge` or `payment_intent` is set.
+  pub fn validate(&self) -> Result<(), String> {
+    if self.charge.is_none() && self.payment_intent.is_none() {
+      if log::log_enabled!(log::Level::Error) {
+        log::error!("A refund requires either `charge` or `payment_intent`");
+      }
+      return Err("Either `charge` or `payment_intent` is required".to_string());
+    }
+
+    Ok(())
+  }
+}
+
+impl std::fmt::Display for RefundParams {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if let Some(r) = &self.charge {
+      write!(f, "charge={};", crate::helper::encode(r))?;
+    }
+    if let Some(r) = &self.payment_intent {
+      write!(f, "payment_intent={};", crate::helper::encode(r))?;
+    }
+    if let Some(r) = self.amount {
+      write!(f, "amount={};", r)?;
+    }
+    if let Some(r) = self.reason {
+      write!(f, "reason={};", r)?;
+    }
+    if let Some(r) = self.refund_application_fee {
+      write!(f, "refund_application_fee={};", r)?;
+    }
+    if let Some(r) = self.reverse_transfer {
+      write!(f, "reverse_transfer={};", r)?;
+    }
+    if let Some(r) = &self.instructions_email {
+      write!(f, "instructions_email={};", crate::helper::encode(r))?;
+    }
+
+    Ok(())
+  }
+}
@@ -1,4 +1,61 @@
-/// Refunds object from 01/12/2023
+/// The email that was sent with refund instructions.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EmailSent {
+  /// The timestamp when the email was sent.
+  pub email_sent_at: i64,
+  /// The recipient’s email address.
+  pub email_sent_to: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Details to display to the customer for a refund that requires an action.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DisplayDetails {
+  /// Contains information about the email sent to the customer.
+  pub email_sent: EmailSent,
+  /// The expiry timestamp.
+  pub expires_at: i64,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// If the refund has a status of `requires_action`, this property describes what the refund needs to continue processing.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct NextAction {
+  /// Contains the refund details.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub display_details: Option<DisplayDetails>,
+  /// Type of the next action to perform.
+  pub r#type: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The reason of a refund, also used by `RefundParams::reason`.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Reason {
+  /// The charge was a duplicate.
+  Duplicate,
+  /// The charge was fraudulent.
+  Fraudulent,
+  /// The customer requested the refund.
+  RequestedByCustomer,
+  /// The authorization of an uncaptured charge expired (set by Stripe).
+  ExpiredUncapturedCharge,
+  /// A reason that is not (yet) part of this enumeration.
+  #[serde(other)]
+  Unknown
+}
+
+impl std::fmt::Display for Reason {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+  }
+}
+
+/// Refunds object from 10/19/2026
 /// 
 /// [Refunds object](https://stripe.com/docs/api/refunds/object)
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -14,21 +71,40 @@ pub struct Response {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub balance_transaction: Option<String>,
   /// ID of the charge that was refunded.
-  pub charge: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub charge: Option<String>,
   /// Time at which the object was created.
   /// Measured in seconds since the Unix epoch.
   pub created: i64,
   /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
   /// Must be a [supported currency](https://stripe.com/docs/currencies).
   pub currency: String,
+  /// An arbitrary string attached to the object.
+  /// Often useful for displaying to users.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// If the refund failed, this balance transaction describes the adjustment made on your account balance that reverses the initial balance transaction.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub failure_balance_transaction: Option<String>,
+  /// If the refund failed, the reason for refund failure if known.
+  /// Possible values are `lost_or_stolen_card`, `expired_or_canceled_card`, `charge_for_pending_refund_disputed`, `insufficient_funds`, `declined`, `merchant_request` or `unknown`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub failure_reason: Option<String>,
+  /// For payment methods without native refund support (e.g. Konbini, PromptPay), the email of the customer to send refund instructions to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub instructions_email: Option<String>,
   /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
   /// This can be useful for storing additional information about the object in a structured format.
   pub metadata: HashMap<String, String>,
+  /// If the refund has a status of `requires_action`, this property describes what the refund needs to continue processing.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub next_action: Option<NextAction>,
   /// ID of the PaymentIntent that was refunded.
-  pub payment_intent: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_intent: Option<String>,
   /// Reason for the refund, either user-provided (`duplicate`, `fraudulent`, or `requested_by_customer`) or generated by Stripe internally (`expired_uncaptured_charge`).
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub reason: Option<String>,
+  pub reason: Option<Reason>,
   /// This is the transaction number that appears on email receipts sent for this refund.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub receipt_number: Option<String>,
@@ -44,5 +120,39 @@ pub struct Response {
   pub status: String,
   /// If the accompanying transfer was reversed, the transfer reversal object.
   /// Only applicable if the charge was created using the destination parameter.
-  pub transfer_reversal: Option<String>
-}
\ No newline at end of file
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub transfer_reversal: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+impl Response {
+  /// Returns an `Info` to retrieve the `crate::transfer_reversal::Response` of this refund.
+  /// 
+  /// # Arguments
+  /// 
+  /// * `client` - The `crate::Client` that sends the request
+  /// * `transfer` - The unique ID of the transfer that was reversed, e.g. `crate::charge::Response::transfer`
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(r) = client.retrieve_refund("REFUND_ID".to_string()).get().await {
+  ///     if let Some(info) = r.retrieve_transfer_reversal(&client, "TRANSFER_ID".to_string()) {
+  ///       let stripe_response = info.get().await;
+  ///     }
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  #[cfg(feature = "transfer")]
+  pub fn retrieve_transfer_reversal<'a>(&self, client: &'a crate::Client, transfer: String) -> Option<crate::transfer_reversal::Info<'a>> {
+    self.transfer_reversal
+      .as_ref()
+      .map(|r| client.retrieve_transfer_reversal(transfer, r.clone()))
+  }
+}
@@ -11,5 +11,7 @@ pub struct ResponseList {
   /// If more than the data received now exists `true` otherwise `false`.
   pub has_more: bool,
   /// All received data.
-  pub data: Vec<Response>
+  pub data: Vec<Response>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
 }
\ No newline at end of file
@@ -0,0 +1,96 @@
+/// The parameters of a report run.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Parameters {
+  /// The set of output columns requested for inclusion in the report run.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub columns: Option<Vec<String>>,
+  /// Connected account ID by which to filter the report run.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub connected_account: Option<String>,
+  /// Currency of objects to be included in the report run.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub currency: Option<String>,
+  /// Ending timestamp of data to be included in the report run.
+  /// Can be any UTC timestamp between 1 second after the user specified `interval_start` and 1 second before this report’s last `data_available_end` value.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub interval_end: Option<i64>,
+  /// Starting timestamp of data to be included in the report run.
+  /// Can be any UTC timestamp between 1 second after this report’s `data_available_start` and 1 second before the user specified `interval_end` value.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub interval_start: Option<i64>,
+  /// Payout ID by which to filter the report run.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payout: Option<String>,
+  /// Category of balance transactions to be included in the report run.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub reporting_category: Option<String>,
+  /// Defaults to `Etc/UTC`.
+  /// The output timezone for all timestamps in the report.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub timezone: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Report Run object from 10/19/2026
+/// 
+/// [Report Run object](https://stripe.com/docs/api/reporting/report_run/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // reporting.report_run
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// If something should go wrong during the run, a message about the failure (populated when `status=failed`).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub error: Option<String>,
+  /// `true` if the report is run on live mode data and `false` if it is run on test mode data.
+  pub livemode: bool,
+  /// Parameters of this report run.
+  pub parameters: Parameters,
+  /// The ID of the [report type](https://stripe.com/docs/reports/report-types) to run, such as `balance.summary.1`.
+  pub report_type: String,
+  /// The file object representing the result of the report run (populated when `status=succeeded`).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub result: Option<crate::file::Response>,
+  /// Status of this report run.
+  /// This will be `pending` when the run is initially created.
+  /// When the run finishes, this will be set to `succeeded` and the `result` field will be populated.
+  /// Rarely, we may encounter an error, at which point this will be set to `failed` and the `error` field will be populated.
+  pub status: Status,
+  /// Timestamp at which this run successfully finished (populated when `status=succeeded`).
+  /// Measured in seconds since the Unix epoch.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub succeeded_at: Option<i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+impl Response {
+  /// Returns an `Info` to download the contents of the `result` of this report run.
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(r) = client.retrieve_report_run("REPORT_RUN_ID".to_string()).get().await {
+  ///     if let Some(contents) = r.retrieve_result_contents(&client) {
+  ///       let stripe_response = contents.download().await;
+  ///     }
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_result_contents<'a>(&self, client: &'a crate::Client) -> Option<crate::file::Info<'a>> {
+    self.result
+      .as_ref()
+      .map(|r| r.retrieve_contents(client))
+  }
+}
@@ -0,0 +1,20 @@
+/// The status of a report run.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+  /// The report is still being generated.
+  Pending,
+  /// The report was generated and `result` contains the file.
+  Succeeded,
+  /// The report could not be generated, see `error`.
+  Failed,
+  /// A status that is not (yet) part of this enumeration.
+  #[serde(other)]
+  Unknown
+}
+
+impl std::fmt::Display for Status {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+  }
+}
@@ -0,0 +1,33 @@
+/// Report Type object from 10/19/2026
+/// 
+/// [Report Type object](https://stripe.com/docs/api/reporting/report_type/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// The [ID of the Report Type](https://stripe.com/docs/reporting/statements/api#available-report-types), such as `balance.summary.1`.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // reporting.report_type
+  /// Most recent time for which this Report Type is available.
+  /// Measured in seconds since the Unix epoch.
+  pub data_available_end: i64,
+  /// Earliest time for which this Report Type is available.
+  /// Measured in seconds since the Unix epoch.
+  pub data_available_start: i64,
+  /// List of column names that are included by default when this Report Type gets run.
+  /// (If the Report Type doesn’t support the `columns` parameter, this will be `None`.)
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub default_columns: Option<Vec<String>>,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// Human-readable name of the Report Type.
+  pub name: String,
+  /// When this Report Type was latest updated.
+  /// Measured in seconds since the Unix epoch.
+  pub updated: i64,
+  /// Version of the Report Type.
+  /// Different versions report with the same ID will have the same purpose, but may take different run parameters or have different result schemas.
+  pub version: i64,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
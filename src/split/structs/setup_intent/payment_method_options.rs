@@ -0,0 +1,56 @@
+/// If the SetupIntent’s payment_method_types includes `acss_debit`, this hash contains the configurations that will be applied to each setup attempt of that type.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentMethodOptionsAcssDebit {
+  /// Currency supported by the bank account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub currency: Option<String>,
+  /// Bank account verification method.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub verification_method: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// If the SetupIntent’s payment_method_types includes `card`, this hash contains the configurations that will be applied to each setup attempt of that type.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentMethodOptionsCard {
+  /// Selected network to process this SetupIntent on.
+  /// Depends on the available networks of the card attached to the SetupIntent.
+  /// Can be only set confirm-time.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub network: Option<String>,
+  /// We strongly recommend that you rely on our SCA Engine to automatically prompt your customers for authentication based on risk level and [other requirements](https://stripe.com/docs/strong-customer-authentication).
+  /// However, if you wish to request 3D Secure based on logic from your own fraud engine, provide this option.
+  /// Permitted values include: `automatic` or `any`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub request_three_d_secure: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// If the SetupIntent’s payment_method_types includes `us_bank_account`, this hash contains the configurations that will be applied to each setup attempt of that type.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentMethodOptionsUsBankAccount {
+  /// Bank account verification method.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub verification_method: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Payment-method-specific configuration for this SetupIntent.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PaymentMethodOptions {
+  /// If the SetupIntent’s payment_method_types includes `acss_debit`, this hash contains the configurations that will be applied to each setup attempt of that type.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub acss_debit: Option<PaymentMethodOptionsAcssDebit>,
+  /// If the SetupIntent’s payment_method_types includes `card`, this hash contains the configurations that will be applied to each setup attempt of that type.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub card: Option<PaymentMethodOptionsCard>,
+  /// If the SetupIntent’s payment_method_types includes `us_bank_account`, this hash contains the configurations that will be applied to each setup attempt of that type.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub us_bank_account: Option<PaymentMethodOptionsUsBankAccount>,
+  /// The configurations of all payment method types that are not (yet) part of this struct.
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
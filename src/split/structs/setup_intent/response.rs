@@ -0,0 +1,119 @@
+include!("payment_method_options.rs");
+
+/// SetupIntent object from 10/19/2026
+/// 
+/// [SetupIntent object](https://stripe.com/docs/api/setup_intents/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // setup_intent
+  /// ID of the Connect application that created the SetupIntent.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub application: Option<String>,
+  /// If present, the SetupIntent’s payment method will be attached to the in-context Stripe Account.
+  /// It can only be used for this Stripe Account’s own money movement flows like InboundTransfer and OutboundTransfers.
+  /// It cannot be set to true when setting up a PaymentMethod for a Customer, and defaults to false when attaching a PaymentMethod to a Customer.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub attach_to_self: Option<bool>,
+  /// Reason for cancellation of this SetupIntent, one of `abandoned`, `requested_by_customer`, or `duplicate`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cancellation_reason: Option<String>,
+  /// The client secret of this SetupIntent.
+  /// Used for client-side retrieval using a publishable key.
+  /// 
+  /// The client secret can be used to complete payment setup from your frontend.
+  /// It should not be stored, logged, or exposed to anyone other than the customer.
+  /// Make sure that you have TLS enabled on any page that includes the client secret.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub client_secret: Option<String>,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// ID of the Customer this SetupIntent belongs to, if one exists.
+  /// 
+  /// If present, the SetupIntent’s payment method will be attached to the Customer on successful setup.
+  /// Payment methods attached to other Customers cannot be used with this SetupIntent.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub customer: Option<String>,
+  /// An arbitrary string attached to the object.
+  /// Often useful for displaying to users.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// Indicates the directions of money movement for which this payment method is intended to be used.
+  /// 
+  /// Include `inbound` if you intend to use the payment method as the origin to pull funds from.
+  /// Include `outbound` if you intend to use the payment method as the destination to send funds to.
+  /// You can include both if you intend to use the payment method for both purposes.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub flow_directions: Option<Vec<String>>,
+  /// The error encountered in the previous SetupIntent confirmation.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub last_setup_error: Option<crate::payment_intent::LastPaymentError>,
+  /// The most recent SetupAttempt for this SetupIntent.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub latest_attempt: Option<String>,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// ID of the multi use Mandate generated by the SetupIntent.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub mandate: Option<String>,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  pub metadata: HashMap<String, String>,
+  /// If present, this property tells you what actions you need to take in order for your customer to continue payment setup.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub next_action: Option<crate::payment_intent::NextAction>,
+  /// The account (if any) for which the setup is intended.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub on_behalf_of: Option<String>,
+  /// ID of the payment method used with this SetupIntent.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_method: Option<String>,
+  /// Payment-method-specific configuration for this SetupIntent.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payment_method_options: Option<PaymentMethodOptions>,
+  /// The list of payment method types (e.g. card) that this SetupIntent is allowed to set up.
+  pub payment_method_types: Vec<String>,
+  /// ID of the single_use Mandate generated by the SetupIntent.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub single_use_mandate: Option<String>,
+  /// [Status](https://stripe.com/docs/payments/intents#intent-statuses) of this SetupIntent, one of `requires_payment_method`, `requires_confirmation`, `requires_action`, `processing`, `canceled`, or `succeeded`.
+  pub status: String,
+  /// Indicates how the payment method is intended to be used in the future.
+  /// 
+  /// Use `on_session` if you intend to only reuse the payment method when the customer is in your checkout flow.
+  /// Use `off_session` if your customer may or may not be in your checkout flow.
+  /// If not provided, this value defaults to `off_session`.
+  pub usage: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+impl Response {
+  /// Returns an `Info` to retrieve the multi use `crate::mandate::Response` generated by this SetupIntent (or the single use one if there is no multi use mandate).
+  /// 
+  /// # Example
+  /// ```
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = ezstripe::Client::new("SECRET_KEY");
+  ///   
+  ///   if let Ok(r) = client.retrieve_setup_intent("SETUP_INTENT_ID".to_string()).get().await {
+  ///     if let Some(mandate) = r.retrieve_mandate(&client) {
+  ///       let stripe_response = mandate.get().await;
+  ///     }
+  ///   }
+  ///   
+  ///   // ...
+  /// }
+  /// ```
+  pub fn retrieve_mandate<'a>(&self, client: &'a crate::Client) -> Option<crate::mandate::Info<'a>> {
+    self.mandate
+      .as_ref()
+      .or(self.single_use_mandate.as_ref())
+      .map(|r| client.retrieve_mandate(r.clone()))
+  }
+}
@@ -0,0 +1,67 @@
+/// Determines how to handle [prorations](https://stripe.com/docs/subscriptions/billing-cycle#prorations) when the billing cycle changes.
+/// 
+/// Use it with `format!` to create the body of a request, e.g. `format!("proration_behavior={};", ProrationBehavior::None)`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProrationBehavior {
+  /// Create prorations (default).
+  CreateProrations,
+  /// Disable creating prorations.
+  None,
+  /// Create prorations and invoice them immediately.
+  AlwaysInvoice
+}
+
+impl std::fmt::Display for ProrationBehavior {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::CreateProrations => write!(f, "create_prorations"),
+      Self::None => write!(f, "none"),
+      Self::AlwaysInvoice => write!(f, "always_invoice")
+    }
+  }
+}
+
+/// The value of the `billing_cycle_anchor` parameter.
+/// 
+/// Use it with `format!` to create the body of a request, e.g. `format!("billing_cycle_anchor={};", BillingCycleAnchor::Now)`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BillingCycleAnchor {
+  /// Reset the billing cycle anchor to the current time (only when updating a subscription).
+  Now,
+  /// Keep the current billing cycle anchor (only when updating a subscription).
+  Unchanged,
+  /// A future timestamp to anchor the billing cycle of a new subscription.
+  /// Measured in seconds since the Unix epoch.
+  Timestamp(i64)
+}
+
+impl std::fmt::Display for BillingCycleAnchor {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Now => write!(f, "now"),
+      Self::Unchanged => write!(f, "unchanged"),
+      Self::Timestamp(r) => write!(f, "{}", r)
+    }
+  }
+}
+
+/// The value of the `trial_end` parameter.
+/// 
+/// Use it with `format!` to create the body of a request, e.g. `format!("trial_end={};", TrialEnd::Now)`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TrialEnd {
+  /// End the trial immediately.
+  Now,
+  /// The end of the trial period.
+  /// Measured in seconds since the Unix epoch.
+  Timestamp(i64)
+}
+
+impl std::fmt::Display for TrialEnd {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Now => write!(f, "now"),
+      Self::Timestamp(r) => write!(f, "{}", r)
+    }
+  }
+}
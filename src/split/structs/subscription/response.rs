@@ -0,0 +1,188 @@
+include!("status.rs");
+
+/// Subscription object from 10/19/2026
+/// 
+/// [Subscription object](https://stripe.com/docs/api/subscriptions/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // subscription
+  /// ID of the Connect Application that created the subscription.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub application: Option<String>,
+  /// A non-negative decimal between 0 and 100, with at most two decimal places.
+  /// This represents the percentage of the subscription invoice subtotal that will be transferred to the application owner’s Stripe account.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub application_fee_percent: Option<f64>,
+  /// Determines the date of the first full invoice, and, for plans with `month` or `year` intervals, the day of the month for subsequent invoices.
+  /// Measured in seconds since the Unix epoch.
+  pub billing_cycle_anchor: i64,
+  /// A date in the future at which the subscription will automatically get canceled.
+  /// Measured in seconds since the Unix epoch.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cancel_at: Option<i64>,
+  /// If the subscription has been canceled with the `at_period_end` flag set to `true`, `cancel_at_period_end` on the subscription will be `true`.
+  /// You can use this attribute to determine whether a subscription that has a status of active is scheduled to be canceled at the end of the current period.
+  pub cancel_at_period_end: bool,
+  /// If the subscription has been canceled, the date of that cancellation.
+  /// If the subscription was canceled with `cancel_at_period_end`, `canceled_at` will reflect the time of the most recent update request, not the end of the subscription period when the subscription is automatically moved to a canceled state.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub canceled_at: Option<i64>,
+  /// Details about why this subscription was cancelled.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cancellation_details: Option<CancellationDetails>,
+  /// Either `charge_automatically`, or `send_invoice`.
+  /// When charging automatically, Stripe will attempt to pay this subscription at the end of the cycle using the default source attached to the customer.
+  /// When sending an invoice, Stripe will email your customer an invoice with payment instructions and mark the subscription as `active`.
+  pub collection_method: String,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+  /// Must be a [supported currency](https://stripe.com/docs/currencies).
+  pub currency: String,
+  /// End of the current period that the subscription has been invoiced for.
+  /// At the end of this period, a new invoice will be created.
+  pub current_period_end: i64,
+  /// Start of the current period that the subscription has been invoiced for.
+  pub current_period_start: i64,
+  /// ID of the customer who owns the subscription.
+  pub customer: String,
+  /// Number of days a customer has to pay invoices generated by this subscription.
+  /// This value will be `None` for subscriptions where `collection_method=charge_automatically`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub days_until_due: Option<u64>,
+  /// ID of the default payment method for the subscription.
+  /// It must belong to the customer associated with the subscription.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub default_payment_method: Option<String>,
+  /// ID of the default payment source for the subscription.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub default_source: Option<String>,
+  /// The subscription’s description, meant to be displayable to the customer.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// If the subscription has ended, the date the subscription ended.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub ended_at: Option<i64>,
+  /// List of subscription items, each with an attached price.
+  pub items: crate::subscription_item::ResponseList,
+  /// The most recent invoice this subscription has generated.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub latest_invoice: Option<String>,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  pub metadata: HashMap<String, String>,
+  /// Specifies the approximate timestamp on which any pending invoice items will be billed according to the schedule provided at `pending_invoice_item_interval`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub next_pending_invoice_item_invoice: Option<i64>,
+  /// The account (if any) the charge was made on behalf of for charges associated with this subscription.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub on_behalf_of: Option<String>,
+  /// If specified, payment collection for this subscription will be paused.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub pause_collection: Option<PauseCollection>,
+  /// You can use this SetupIntent to collect user authentication when creating a subscription without immediate payment or updating a subscription’s payment method, allowing you to optimize for off-session payments.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub pending_setup_intent: Option<String>,
+  /// If specified, [pending updates](https://stripe.com/docs/billing/subscriptions/pending-updates) that will be applied to the subscription once the `latest_invoice` has been paid.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub pending_update: Option<PendingUpdate>,
+  /// The schedule attached to the subscription.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub schedule: Option<String>,
+  /// Date when the subscription was first created.
+  /// The date might differ from the `created` date due to backdating.
+  pub start_date: i64,
+  /// The status of the subscription, see `Status`.
+  pub status: Status,
+  /// ID of the test clock this subscription belongs to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub test_clock: Option<String>,
+  /// If the subscription has a trial, the end of that trial.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub trial_end: Option<i64>,
+  /// Settings related to subscription trials.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub trial_settings: Option<TrialSettings>,
+  /// If the subscription has a trial, the beginning of that trial.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub trial_start: Option<i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Details about why a subscription was cancelled.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CancellationDetails {
+  /// Additional comments about why the user canceled the subscription, if the subscription was canceled explicitly by the user.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub comment: Option<String>,
+  /// The customer submitted reason for why they canceled, if the subscription was canceled explicitly by the user.
+  /// One of `customer_service`, `low_quality`, `missing_features`, `other`, `switched_service`, `too_complex`, `too_expensive` or `unused`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub feedback: Option<String>,
+  /// Why this subscription was canceled.
+  /// One of `cancellation_requested`, `payment_disputed` or `payment_failed`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub reason: Option<String>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Paused payment collection of a subscription.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PauseCollection {
+  /// The payment collection behavior for this subscription while paused.
+  /// One of `keep_as_draft`, `mark_uncollectible`, or `void`.
+  pub behavior: String,
+  /// The time after which the subscription will resume collecting payments.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub resumes_at: Option<i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Updates that will be applied to a subscription once the `latest_invoice` has been paid.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PendingUpdate {
+  /// If the update is applied, determines the date of the first full invoice, and, for plans with `month` or `year` intervals, the day of the month for subsequent invoices.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub billing_cycle_anchor: Option<i64>,
+  /// The point after which the changes reflected by this update will be discarded and no longer applied.
+  pub expires_at: i64,
+  /// List of subscription items, each with an attached plan, that will be set if the update is applied.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub subscription_items: Option<Vec<crate::subscription_item::Response>>,
+  /// Unix timestamp representing the end of the trial period the customer will get before being charged for the first time, if the update is applied.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub trial_end: Option<i64>,
+  /// Indicates if a plan’s `trial_period_days` should be applied to the subscription.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub trial_from_plan: Option<bool>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Settings related to subscription trials.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TrialSettings {
+  /// Defines how a subscription behaves when a free trial ends.
+  pub end_behavior: TrialEndBehavior,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Defines how a subscription behaves when a free trial ends.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TrialEndBehavior {
+  /// Indicates how the subscription should change when the trial ends if the user did not provide a payment method.
+  pub missing_payment_method: MissingPaymentMethod,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,53 @@
+/// The status of a subscription.
+/// 
+/// [Subscription statuses](https://stripe.com/docs/billing/subscriptions/overview#subscription-statuses)
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+  /// The initial payment attempt failed or requires an action of the customer.
+  Incomplete,
+  /// The first invoice was not paid within 23 hours.
+  IncompleteExpired,
+  /// The subscription is in its trial period.
+  Trialing,
+  /// The subscription is in good standing.
+  Active,
+  /// Payment on the latest finalized invoice either failed or wasn’t attempted.
+  PastDue,
+  /// The subscription has been canceled.
+  Canceled,
+  /// The latest invoice hasn’t been paid and Stripe stopped retrying.
+  Unpaid,
+  /// The trial ended without a default payment method and `missing_payment_method` is `pause`.
+  Paused,
+  /// A status that is not (yet) part of this enumeration.
+  #[serde(other)]
+  Unknown
+}
+
+impl std::fmt::Display for Status {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+  }
+}
+
+/// Indicates how a subscription should change when the trial ends if the user did not provide a payment method.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingPaymentMethod {
+  /// Cancel the subscription if a payment method is not attached when the trial ends.
+  Cancel,
+  /// Create an invoice when the trial ends, even if the user did not set up a payment method.
+  CreateInvoice,
+  /// Pause the subscription if a payment method is not attached when the trial ends.
+  Pause,
+  /// A behavior that is not (yet) part of this enumeration.
+  #[serde(other)]
+  Unknown
+}
+
+impl std::fmt::Display for MissingPaymentMethod {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", serde_json::to_value(self).ok().and_then(|r| r.as_str().map(|r| r.to_string())).unwrap_or_default())
+  }
+}
@@ -0,0 +1,39 @@
+/// Subscription item object from 10/19/2026
+/// 
+/// [Subscription item object](https://stripe.com/docs/api/subscription_items/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // subscription_item
+  /// Define thresholds at which an invoice will be sent, and the related subscription advanced to a new billing period.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub billing_thresholds: Option<BillingThresholds>,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  pub metadata: HashMap<String, String>,
+  /// The price the customer is subscribed to.
+  pub price: crate::price::Response,
+  /// The [quantity](https://stripe.com/docs/subscriptions/quantities) of the plan to which the customer should be subscribed.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub quantity: Option<u64>,
+  /// The `subscription` this `subscription_item` belongs to.
+  pub subscription: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Thresholds at which an invoice will be sent.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BillingThresholds {
+  /// Usage threshold that triggers the subscription to create an invoice.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub usage_gte: Option<i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
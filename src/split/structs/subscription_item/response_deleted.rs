@@ -0,0 +1,15 @@
+/// Deleted subscription item object from 10/19/2026
+/// 
+/// [Delete an item](https://stripe.com/docs/api/subscription_items/delete)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ResponseDeleted {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // subscription_item
+  /// Always `true` for a deleted object.
+  pub deleted: bool,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
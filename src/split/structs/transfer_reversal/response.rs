@@ -0,0 +1,36 @@
+/// Transfer Reversal object from 10/19/2026
+/// 
+/// [Transfer Reversal object](https://stripe.com/docs/api/transfer_reversals/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // transfer_reversal
+  /// Amount, in cents.
+  pub amount: i64,
+  /// Balance transaction that describes the impact on your account balance.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub balance_transaction: Option<String>,
+  /// Time at which the object was created.
+  /// Measured in seconds since the Unix epoch.
+  pub created: i64,
+  /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+  /// Must be a [supported currency](https://stripe.com/docs/currencies).
+  pub currency: String,
+  /// Linked payment refund for the transfer reversal.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub destination_payment_refund: Option<String>,
+  /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+  /// This can be useful for storing additional information about the object in a structured format.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metadata: Option<HashMap<String, String>>,
+  /// ID of the refund responsible for the transfer reversal.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub source_refund: Option<String>,
+  /// ID of the transfer that was reversed.
+  pub transfer: String,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
@@ -0,0 +1,61 @@
+/// Usage record object from 10/19/2026
+/// 
+/// [Usage record object](https://stripe.com/docs/api/usage_records/object)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Response {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // usage_record
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// The usage quantity for the specified date.
+  pub quantity: u64,
+  /// The ID of the subscription item this usage record contains data for.
+  pub subscription_item: String,
+  /// The timestamp when this usage occurred.
+  pub timestamp: i64,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// Usage record summary object from 10/19/2026
+/// 
+/// [Usage record summary object](https://stripe.com/docs/api/usage_records/subscription_item_summary_list)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Summary {
+  /// Unique identifier for the object.
+  pub id: String,
+  /// String representing the object’s type.
+  /// Objects of the same type share the same value.
+  pub object: String, // usage_record_summary
+  /// The invoice in which this usage period has been billed for.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub invoice: Option<String>,
+  /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+  pub livemode: bool,
+  /// The usage period.
+  pub period: Period,
+  /// The ID of the subscription item this summary is describing.
+  pub subscription_item: String,
+  /// The total usage within this usage period.
+  pub total_usage: i64,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}
+
+/// The period of a `Summary`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Period {
+  /// The end date of this usage period.
+  /// All usage up to and including this point in time is included.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub end: Option<i64>,
+  /// The start date of this usage period.
+  /// All usage after this point in time is included.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub start: Option<i64>,
+  #[serde(flatten)]
+  pub extra: crate::Extra
+}